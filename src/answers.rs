@@ -0,0 +1,290 @@
+use crate::input::input_dir;
+use crate::solution::Answer;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct ExpectedAnswers {
+    answers: BTreeMap<(u8, usize), Answer>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verification<'a> {
+    Correct,
+    Incorrect(&'a Answer),
+    Missing,
+}
+
+impl ExpectedAnswers {
+    pub fn get(&self, day: u8, part: usize) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u8, part: usize, answer: Answer) {
+        self.answers.insert((day, part), answer);
+    }
+
+    // Answers are compared by their printed form, so a quoted number in the file still matches
+    pub fn verify(&self, day: u8, part: usize, answer: &Answer) -> Verification<'_> {
+        match self.get(day, part) {
+            Some(expected) if expected.to_string() == answer.to_string() => Verification::Correct,
+            Some(expected) => Verification::Incorrect(expected),
+            None => Verification::Missing,
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, AnswersError> {
+        let mut expected_answers = Self::default();
+        let mut current_day = None;
+        for (i, line) in text.lines().enumerate() {
+            let syntax_error = |message: String| AnswersError::Syntax {
+                line: i + 1,
+                message,
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[') {
+                let day = table
+                    .strip_suffix(']')
+                    .and_then(|table| table.trim().strip_prefix("day_"))
+                    .and_then(|day| day.parse::<u8>().ok())
+                    .ok_or_else(|| syntax_error(format!("invalid table '{}'", line)))?;
+                current_day = Some(day);
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| syntax_error(format!("expected 'key = value', found '{}'", line)))?;
+            let part = match key.trim() {
+                "part_1" => 1,
+                "part_2" => 2,
+                key => return Err(syntax_error(format!("unknown key '{}'", key))),
+            };
+            let day = current_day
+                .ok_or_else(|| syntax_error(String::from("answer outside of a [day_N] table")))?;
+            if expected_answers.get(day, part).is_some() {
+                return Err(syntax_error(format!(
+                    "duplicate answer for day {}, part {}",
+                    day, part
+                )));
+            }
+            let answer = parse_value(value.trim()).map_err(syntax_error)?;
+            expected_answers.insert(day, part, answer);
+        }
+        Ok(expected_answers)
+    }
+}
+
+fn parse_value(value: &str) -> Result<Answer, String> {
+    let Some(quoted_text) = value.strip_prefix('"') else {
+        let value = value.split('#').next().unwrap_or_default().trim();
+        return value
+            .parse::<i64>()
+            .map(Answer::Integer)
+            .map_err(|_| format!("expected an integer or a string, found '{}'", value));
+    };
+
+    let mut text = String::new();
+    let mut characters = quoted_text.chars();
+    while let Some(c) = characters.next() {
+        match c {
+            '"' => {
+                let rest = characters.as_str().trim();
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    return Err(format!("unexpected '{}' after string", rest));
+                }
+                return Ok(Answer::Text(text));
+            }
+            '\\' => match characters.next() {
+                Some('n') => text.push('\n'),
+                Some('t') => text.push('\t'),
+                Some('"') => text.push('"'),
+                Some('\\') => text.push('\\'),
+                escape => {
+                    let escape = escape.map(String::from).unwrap_or_default();
+                    return Err(format!("unknown escape sequence '\\{}'", escape));
+                }
+            },
+            c => text.push(c),
+        }
+    }
+    Err(String::from("unterminated string"))
+}
+
+fn format_value(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(value) => value.to_string(),
+        Answer::Text(text) => {
+            let mut quoted_text = String::from('"');
+            for c in text.chars() {
+                match c {
+                    '\n' => quoted_text.push_str("\\n"),
+                    '\t' => quoted_text.push_str("\\t"),
+                    '"' => quoted_text.push_str("\\\""),
+                    '\\' => quoted_text.push_str("\\\\"),
+                    c => quoted_text.push(c),
+                }
+            }
+            quoted_text.push('"');
+            quoted_text
+        }
+    }
+}
+
+impl fmt::Display for ExpectedAnswers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "# Expected answers checked by `aoc verify`, updated via `aoc verify --record`"
+        )?;
+        let mut current_day = None;
+        for ((day, part), answer) in &self.answers {
+            if current_day != Some(*day) {
+                writeln!(f, "\n[day_{}]", day)?;
+                current_day = Some(*day);
+            }
+            writeln!(f, "part_{} = {}", part, format_value(answer))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, error: io::Error },
+    Syntax { line: usize, message: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => {
+                write!(
+                    f,
+                    "Could not access answers file {}: {}",
+                    path.display(),
+                    error
+                )
+            }
+            Self::Syntax { line, message } => {
+                write!(f, "Invalid answers file, line {}: {}", line, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+pub fn default_answers_path() -> PathBuf {
+    input_dir().join("answers.toml")
+}
+
+// A missing answers file is treated as a file without any recorded answers
+pub fn read_answers(path: &Path) -> Result<ExpectedAnswers, AnswersError> {
+    match fs::read_to_string(path) {
+        Ok(text) => ExpectedAnswers::parse(&text),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(ExpectedAnswers::default()),
+        Err(error) => Err(AnswersError::Io {
+            path: path.to_path_buf(),
+            error,
+        }),
+    }
+}
+
+pub fn write_answers(path: &Path, expected_answers: &ExpectedAnswers) -> Result<(), AnswersError> {
+    fs::write(path, expected_answers.to_string()).map_err(|error| AnswersError::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_parsing() {
+        let expected_answers = ExpectedAnswers::parse(
+            "# comment
+            [day_1]
+            part_1 = 24000
+            part_2 = -3 # comment
+
+            [day_5]
+            part_1 = \"CMZ\"
+            part_2 = \"a\\n\\\"b\\\"\\\\\"",
+        )
+        .unwrap();
+        assert_eq!(expected_answers.get(1, 1), Some(&Answer::Integer(24000)));
+        assert_eq!(expected_answers.get(1, 2), Some(&Answer::Integer(-3)));
+        assert_eq!(expected_answers.get(2, 1), None);
+        assert_eq!(expected_answers.get(5, 1), Some(&Answer::from("CMZ")));
+        assert_eq!(
+            expected_answers.get(5, 2),
+            Some(&Answer::from("a\n\"b\"\\"))
+        );
+    }
+
+    #[test]
+    fn test_answers_parsing_errors() {
+        let syntax_error_line = |text: &str| match ExpectedAnswers::parse(text) {
+            Err(AnswersError::Syntax { line, .. }) => line,
+            _ => 0,
+        };
+        assert_eq!(syntax_error_line("part_1 = 1"), 1);
+        assert_eq!(syntax_error_line("[day_1]\n\n[day_x]"), 3);
+        assert_eq!(syntax_error_line("[day_1]\npart_3 = 1"), 2);
+        assert_eq!(syntax_error_line("[day_1]\npart_1 = 1\npart_1 = 2"), 3);
+        assert_eq!(syntax_error_line("[day_1]\npart_1 = abc"), 2);
+        assert_eq!(syntax_error_line("[day_1]\npart_1 = \"abc"), 2);
+        assert_eq!(syntax_error_line("[day_1]\npart_1 = \"a\" b"), 2);
+        assert_eq!(syntax_error_line("[day_1]\npart_1"), 2);
+    }
+
+    #[test]
+    fn test_answers_formatting_round_trip() {
+        let mut expected_answers = ExpectedAnswers::default();
+        expected_answers.insert(10, 2, Answer::from("##..\n..##"));
+        expected_answers.insert(10, 1, Answer::from(13140));
+        expected_answers.insert(2, 1, Answer::from("say \"hi\" \\o/"));
+        let text = expected_answers.to_string();
+        assert!(text.contains("[day_10]\npart_1 = 13140\npart_2 = \"##..\\n..##\"\n"));
+        assert_eq!(ExpectedAnswers::parse(&text).unwrap(), expected_answers);
+    }
+
+    #[test]
+    fn test_verification() {
+        let mut expected_answers = ExpectedAnswers::default();
+        expected_answers.insert(1, 1, Answer::from(24000));
+        expected_answers.insert(1, 2, Answer::from("45000"));
+        assert_eq!(
+            expected_answers.verify(1, 1, &Answer::from(24000)),
+            Verification::Correct
+        );
+        assert_eq!(
+            expected_answers.verify(1, 2, &Answer::from(45000)),
+            Verification::Correct
+        );
+        assert_eq!(
+            expected_answers.verify(1, 1, &Answer::from(1)),
+            Verification::Incorrect(&Answer::Integer(24000))
+        );
+        assert_eq!(
+            expected_answers.verify(2, 1, &Answer::from(1)),
+            Verification::Missing
+        );
+    }
+
+    #[test]
+    fn test_read_missing_answers_file() {
+        let expected_answers = read_answers(Path::new("does/not/exist.toml")).unwrap();
+        assert_eq!(expected_answers, ExpectedAnswers::default());
+    }
+}
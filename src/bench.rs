@@ -0,0 +1,176 @@
+use crate::error::{SolveError, SolveResult};
+use crate::solution::Solution;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut samples = samples.to_vec();
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: (samples[(samples.len() - 1) / 2] + samples[samples.len() / 2]) / 2,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Benchmark {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Timings,
+    pub part_1: Timings,
+    pub part_2: Timings,
+}
+
+impl Benchmark {
+    pub fn phases(&self) -> [(&'static str, &Timings); 3] {
+        [
+            ("parse", &self.parse),
+            ("part_1", &self.part_1),
+            ("part_2", &self.part_2),
+        ]
+    }
+}
+
+// A part the puzzle doesn't have is still timed, it just has nothing to compute
+fn check_timed_part<T>(result: SolveResult<T>) -> SolveResult<()> {
+    match result {
+        Ok(_) | Err(SolveError::NotApplicable { .. }) => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+// Every iteration parses the input once and solves both parts on the freshly parsed input
+pub fn benchmark_solution<S: Solution>(
+    input: &str,
+    iterations: usize,
+) -> SolveResult<[Timings; 3]> {
+    let mut samples = [(); 3].map(|_| Vec::with_capacity(iterations));
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let parsed_input = black_box(S::parse(black_box(input))?);
+        samples[0].push(start.elapsed());
+
+        let start = Instant::now();
+        check_timed_part(black_box(S::part1(&parsed_input)))?;
+        samples[1].push(start.elapsed());
+
+        let start = Instant::now();
+        check_timed_part(black_box(S::part2(&parsed_input)))?;
+        samples[2].push(start.elapsed());
+    }
+    Ok(samples.map(|phase_samples| Timings::from_samples(&phase_samples)))
+}
+
+pub fn to_csv(benchmarks: &[Benchmark]) -> String {
+    let mut csv = String::from("day,phase,iterations,min_ns,median_ns,max_ns\n");
+    for benchmark in benchmarks {
+        for (phase, timings) in benchmark.phases() {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                benchmark.day,
+                phase,
+                benchmark.iterations,
+                timings.min.as_nanos(),
+                timings.median.as_nanos(),
+                timings.max.as_nanos()
+            ));
+        }
+    }
+    csv
+}
+
+pub fn to_json(benchmarks: &[Benchmark]) -> String {
+    let entries = benchmarks
+        .iter()
+        .flat_map(|benchmark| {
+            benchmark.phases().map(|(phase, timings)| {
+                format!(
+                    "  {{\"day\": {}, \"phase\": \"{}\", \"iterations\": {}, \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                    benchmark.day,
+                    phase,
+                    benchmark.iterations,
+                    timings.min.as_nanos(),
+                    timings.median.as_nanos(),
+                    timings.max.as_nanos()
+                )
+            })
+        })
+        .collect::<Vec<String>>();
+    if entries.is_empty() {
+        String::from("[]\n")
+    } else {
+        format!("[\n{}\n]\n", entries.join(",\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_01::CalorieCounting;
+
+    fn timings(min: u64, median: u64, max: u64) -> Timings {
+        Timings {
+            min: Duration::from_nanos(min),
+            median: Duration::from_nanos(median),
+            max: Duration::from_nanos(max),
+        }
+    }
+
+    fn example_benchmark() -> Benchmark {
+        Benchmark {
+            day: 3,
+            iterations: 5,
+            parse: timings(1, 2, 3),
+            part_1: timings(10, 20, 30),
+            part_2: timings(100, 200, 300),
+        }
+    }
+
+    #[test]
+    fn test_timings_from_samples() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_nanos);
+        assert_eq!(Timings::from_samples(&samples), timings(1, 3, 5));
+        let samples = [8, 2, 4, 1].map(Duration::from_nanos);
+        assert_eq!(Timings::from_samples(&samples), timings(1, 3, 8));
+    }
+
+    #[test]
+    fn test_benchmark_solution() {
+        let [parse, part_1, part_2] =
+            benchmark_solution::<CalorieCounting>("1\n2\n\n3\n\n4", 3).unwrap();
+        assert!(parse.min <= parse.median && parse.median <= parse.max);
+        assert!(part_1.min <= part_1.max);
+        assert!(part_2.min <= part_2.max);
+        assert!(benchmark_solution::<CalorieCounting>("1\nx", 3).is_err());
+    }
+
+    #[test]
+    fn test_csv_output() {
+        assert_eq!(
+            to_csv(&[example_benchmark()]),
+            "day,phase,iterations,min_ns,median_ns,max_ns
+3,parse,5,1,2,3
+3,part_1,5,10,20,30
+3,part_2,5,100,200,300
+"
+        );
+    }
+
+    #[test]
+    fn test_json_output() {
+        assert_eq!(to_json(&[]), "[]\n");
+        let json = to_json(&[example_benchmark()]);
+        assert!(json.starts_with("[\n  {\"day\": 3, \"phase\": \"parse\", \"iterations\": 5, \"min_ns\": 1, \"median_ns\": 2, \"max_ns\": 3},\n"));
+        assert!(json.ends_with("\"max_ns\": 300}\n]\n"));
+    }
+}
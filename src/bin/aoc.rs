@@ -0,0 +1,523 @@
+use aoc2022::answers::{default_answers_path, read_answers, write_answers, Verification};
+use aoc2022::bench::{to_csv, to_json, Benchmark};
+use aoc2022::cli::exit_with_error;
+use aoc2022::input::{read_input, InputSource};
+use aoc2022::registry::{find_example_solver, find_solver, Solver, SOLVERS};
+use aoc2022::scaffold::create_day;
+use aoc2022::{Answer, SolveError};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+const USAGE: &str = "Usage:
+    aoc run <day|all> [--part <1|2>] [--input <path|->] [--example]
+    aoc verify [<day|all>] [--record] [--example]
+    aoc bench <day|all> [--iterations <n>] [--input <path|->] [--output <path.csv|path.json>] [--example]
+    aoc new <day> [<title>]
+    aoc list
+
+Inputs are read from $AOC_INPUT_DIR/data_day_<day>.txt by default.
+Expected answers are read from and recorded to $AOC_INPUT_DIR/answers.toml.
+--example solves with the parameters of the puzzle examples, like row 10 on day 15.";
+
+enum DaySelection {
+    All,
+    Single(u8),
+}
+
+struct RunOptions {
+    parts: Vec<usize>,
+    input: Option<String>,
+    example: bool,
+}
+
+enum OutputFormat {
+    Csv,
+    Json,
+}
+
+struct BenchOptions {
+    iterations: usize,
+    input: Option<String>,
+    output: Option<(PathBuf, OutputFormat)>,
+    example: bool,
+}
+
+struct VerifyOptions {
+    record: bool,
+    example: bool,
+}
+
+enum Command {
+    Run(DaySelection, RunOptions),
+    Bench(DaySelection, BenchOptions),
+    Verify(DaySelection, VerifyOptions),
+    New(u8, String),
+    List,
+}
+
+fn parse_day_selection(argument: &str) -> Result<DaySelection, String> {
+    if argument == "all" {
+        return Ok(DaySelection::All);
+    }
+    match argument.parse() {
+        Ok(day) if find_solver(day).is_some() => Ok(DaySelection::Single(day)),
+        _ => Err(format!("There is no solution for day '{}'", argument)),
+    }
+}
+
+fn parse_part(argument: Option<&String>) -> Result<usize, String> {
+    match argument.map(|part| part.as_str()) {
+        Some("1") => Ok(1),
+        Some("2") => Ok(2),
+        Some(part) => Err(format!("Part has to be 1 or 2, but was '{}'", part)),
+        None => Err(String::from("Missing value for --part")),
+    }
+}
+
+fn parse_output(argument: Option<&String>) -> Result<(PathBuf, OutputFormat), String> {
+    let path = PathBuf::from(argument.ok_or("Missing value for --output")?);
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("csv") => Ok((path, OutputFormat::Csv)),
+        Some("json") => Ok((path, OutputFormat::Json)),
+        _ => Err(format!(
+            "Output file '{}' has to end with .csv or .json",
+            path.display()
+        )),
+    }
+}
+
+fn parse_arguments(arguments: &[String]) -> Result<Command, String> {
+    match arguments.first().map(|command| command.as_str()) {
+        Some("run") => {
+            let day_selection = match arguments.get(1) {
+                Some(day) => parse_day_selection(day)?,
+                None => return Err(String::from("Missing day to run")),
+            };
+            let mut run_options = RunOptions {
+                parts: vec![1, 2],
+                input: None,
+                example: false,
+            };
+            let mut options = arguments[2..].iter();
+            while let Some(option) = options.next() {
+                match option.as_str() {
+                    "--part" | "-p" => run_options.parts = vec![parse_part(options.next())?],
+                    "--example" => run_options.example = true,
+                    "--input" | "-i" => match options.next() {
+                        Some(input) => run_options.input = Some(input.clone()),
+                        None => return Err(String::from("Missing value for --input")),
+                    },
+                    _ => return Err(format!("Unknown option '{}'", option)),
+                }
+            }
+            if matches!(day_selection, DaySelection::All) && run_options.input.is_some() {
+                return Err(String::from("--input can only be used for a single day"));
+            }
+            Ok(Command::Run(day_selection, run_options))
+        }
+        Some("bench") => {
+            let day_selection = match arguments.get(1) {
+                Some(day) => parse_day_selection(day)?,
+                None => return Err(String::from("Missing day to benchmark")),
+            };
+            let mut bench_options = BenchOptions {
+                iterations: 10,
+                input: None,
+                output: None,
+                example: false,
+            };
+            let mut options = arguments[2..].iter();
+            while let Some(option) = options.next() {
+                match option.as_str() {
+                    "--iterations" | "-n" => {
+                        bench_options.iterations = match options.next().map(|n| n.parse()) {
+                            Some(Ok(iterations)) if iterations > 0 => iterations,
+                            _ => return Err(String::from("--iterations needs a positive number")),
+                        }
+                    }
+                    "--input" | "-i" => match options.next() {
+                        Some(input) => bench_options.input = Some(input.clone()),
+                        None => return Err(String::from("Missing value for --input")),
+                    },
+                    "--output" | "-o" => bench_options.output = Some(parse_output(options.next())?),
+                    "--example" => bench_options.example = true,
+                    _ => return Err(format!("Unknown option '{}'", option)),
+                }
+            }
+            if matches!(day_selection, DaySelection::All) && bench_options.input.is_some() {
+                return Err(String::from("--input can only be used for a single day"));
+            }
+            Ok(Command::Bench(day_selection, bench_options))
+        }
+        Some("verify") => {
+            let mut day_selection = DaySelection::All;
+            let mut verify_options = VerifyOptions {
+                record: false,
+                example: false,
+            };
+            for argument in &arguments[1..] {
+                match argument.as_str() {
+                    "--record" => verify_options.record = true,
+                    "--example" => verify_options.example = true,
+                    option if option.starts_with('-') => {
+                        return Err(format!("Unknown option '{}'", option))
+                    }
+                    day => day_selection = parse_day_selection(day)?,
+                }
+            }
+            Ok(Command::Verify(day_selection, verify_options))
+        }
+        Some("new") => {
+            let day = match arguments.get(1).map(|day| day.parse::<u8>()) {
+                Some(Ok(day)) if (1..=25).contains(&day) => day,
+                Some(_) => return Err(String::from("Day has to be a number from 1 to 25")),
+                None => return Err(String::from("Missing day to create")),
+            };
+            let title = arguments[2..].join(" ");
+            Ok(Command::New(day, title))
+        }
+        Some("list") => Ok(Command::List),
+        Some(command) => Err(format!("Unknown command '{}'", command)),
+        None => Err(String::from("Missing command")),
+    }
+}
+
+// Multi-line answers like CRT images start on their own line
+fn format_answer(answer: &Answer) -> String {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        format!("\n{}", answer)
+    } else {
+        format!(" {}", answer)
+    }
+}
+
+fn describe_error(input_source: &InputSource, error: &SolveError) -> String {
+    match error {
+        SolveError::Parse(error) => format!("Could not parse {}:\n{}", input_source, error),
+        SolveError::NoAnswer { .. } | SolveError::NotApplicable { .. } => {
+            format!("Could not solve {}:\n{}", input_source, error)
+        }
+    }
+}
+
+fn get_solvers(day_selection: DaySelection, example: bool) -> Vec<&'static Solver> {
+    let days = match day_selection {
+        DaySelection::All => SOLVERS.iter().map(|solver| solver.day).collect(),
+        DaySelection::Single(day) => vec![day],
+    };
+    days.into_iter()
+        .map(|day| {
+            if example {
+                find_example_solver(day).unwrap()
+            } else {
+                find_solver(day).unwrap()
+            }
+        })
+        .collect()
+}
+
+fn run(day_selection: DaySelection, run_options: &RunOptions) {
+    for solver in get_solvers(day_selection, run_options.example) {
+        let input_source = InputSource::from_argument(solver.day, run_options.input.as_deref());
+        let input = read_input(&input_source).unwrap_or_else(|error| exit_with_error(error));
+        for part in &run_options.parts {
+            let answer = match solver.solve(*part, &input) {
+                Ok(answer) => answer,
+                Err(SolveError::NotApplicable { .. }) => {
+                    println!("Day {}, part {}: not applicable", solver.day, part);
+                    continue;
+                }
+                Err(error) => exit_with_error(describe_error(&input_source, &error)),
+            };
+            println!(
+                "Day {}, part {}:{}",
+                solver.day,
+                part,
+                format_answer(&answer)
+            );
+        }
+    }
+}
+
+fn verify(day_selection: DaySelection, verify_options: &VerifyOptions) {
+    let record = verify_options.record;
+    let answers_path = default_answers_path();
+    let mut expected_answers =
+        read_answers(&answers_path).unwrap_or_else(|error| exit_with_error(error));
+    let mut failures = 0;
+    let mut checked_answers = 0;
+
+    for solver in get_solvers(day_selection, verify_options.example) {
+        let input_source = InputSource::Default(solver.day);
+        let input = match read_input(&input_source) {
+            Ok(input) => input,
+            Err(error) => {
+                println!("Day {}: {}", solver.day, error);
+                failures += 2;
+                continue;
+            }
+        };
+        for part in [1, 2] {
+            let answer = match solver.solve(part, &input) {
+                Ok(answer) => answer,
+                Err(SolveError::NotApplicable { .. }) => {
+                    println!("Day {}, part {}: not applicable", solver.day, part);
+                    continue;
+                }
+                Err(error) => {
+                    println!(
+                        "Day {}, part {}: {}",
+                        solver.day,
+                        part,
+                        describe_error(&input_source, &error)
+                    );
+                    failures += 1;
+                    continue;
+                }
+            };
+            checked_answers += 1;
+            if record {
+                println!(
+                    "Day {}, part {}: recorded{}",
+                    solver.day,
+                    part,
+                    format_answer(&answer)
+                );
+                expected_answers.insert(solver.day, part, answer);
+                continue;
+            }
+            match expected_answers.verify(solver.day, part, &answer) {
+                Verification::Correct => println!("Day {}, part {}: ok", solver.day, part),
+                Verification::Incorrect(expected) => {
+                    println!("Day {}, part {}: mismatch", solver.day, part);
+                    println!("  expected:{}", format_answer(expected));
+                    println!("  found:{}", format_answer(&answer));
+                    failures += 1;
+                }
+                Verification::Missing => {
+                    println!(
+                        "Day {}, part {}: no recorded answer, found{}",
+                        solver.day,
+                        part,
+                        format_answer(&answer)
+                    );
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if record {
+        write_answers(&answers_path, &expected_answers)
+            .unwrap_or_else(|error| exit_with_error(error));
+        println!(
+            "\nRecorded {} answers in {}",
+            checked_answers,
+            answers_path.display()
+        );
+    } else {
+        println!("\n{} answers checked, {} failed", checked_answers, failures);
+    }
+    if failures > 0 {
+        process::exit(1);
+    }
+}
+
+fn print_benchmark(benchmark: &Benchmark) {
+    for (phase, timings) in benchmark.phases() {
+        println!(
+            "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+            benchmark.day,
+            phase,
+            format!("{:.2?}", timings.min),
+            format!("{:.2?}", timings.median),
+            format!("{:.2?}", timings.max)
+        );
+    }
+}
+
+fn bench(day_selection: DaySelection, bench_options: &BenchOptions) {
+    println!(
+        "Day  Phase   {:>12}  {:>12}  {:>12}",
+        "Min", "Median", "Max"
+    );
+    let mut benchmarks = Vec::new();
+    for solver in get_solvers(day_selection, bench_options.example) {
+        let input_source = InputSource::from_argument(solver.day, bench_options.input.as_deref());
+        let input = read_input(&input_source).unwrap_or_else(|error| exit_with_error(error));
+        let benchmark = solver
+            .benchmark(&input, bench_options.iterations)
+            .unwrap_or_else(|error| exit_with_error(describe_error(&input_source, &error)));
+        print_benchmark(&benchmark);
+        benchmarks.push(benchmark);
+    }
+
+    if let Some((path, output_format)) = &bench_options.output {
+        let output = match output_format {
+            OutputFormat::Csv => to_csv(&benchmarks),
+            OutputFormat::Json => to_json(&benchmarks),
+        };
+        fs::write(path, output).unwrap_or_else(|error| {
+            exit_with_error(format!("Could not write {}: {}", path.display(), error))
+        });
+        println!("\nBenchmark results written to {}", path.display());
+    }
+}
+
+fn new(day: u8, title: &str) {
+    if find_solver(day).is_some() {
+        exit_with_error(format!("Day {} already exists", day));
+    }
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let title = if title.is_empty() {
+        format!("Day {}", day)
+    } else {
+        String::from(title)
+    };
+    let written_files =
+        create_day(root, day, &title).unwrap_or_else(|error| exit_with_error(error));
+    for path in &written_files {
+        println!("{}", path.strip_prefix(root).unwrap_or(path).display());
+    }
+
+    // The templates are only formatted for short names, rustfmt is optional though
+    let _ = process::Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args(
+            written_files
+                .iter()
+                .filter(|path| path.extension() == Some("rs".as_ref())),
+        )
+        .status();
+}
+
+fn list() {
+    for solver in SOLVERS {
+        println!("Day {:>2}: {}", solver.day, solver.title);
+    }
+}
+
+fn main() {
+    let arguments = env::args().skip(1).collect::<Vec<String>>();
+    match parse_arguments(&arguments) {
+        Ok(Command::Run(day_selection, run_options)) => run(day_selection, &run_options),
+        Ok(Command::Bench(day_selection, bench_options)) => bench(day_selection, &bench_options),
+        Ok(Command::Verify(day_selection, verify_options)) => {
+            verify(day_selection, &verify_options)
+        }
+        Ok(Command::New(day, title)) => new(day, &title),
+        Ok(Command::List) => list(),
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arguments(arguments: &str) -> Vec<String> {
+        arguments.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_run_arguments() {
+        assert!(matches!(
+            parse_arguments(&arguments("run 7 --part 2")),
+            Ok(Command::Run(DaySelection::Single(7), options)) if options.parts == vec![2]
+        ));
+        assert!(matches!(
+            parse_arguments(&arguments("run all")),
+            Ok(Command::Run(DaySelection::All, options)) if options.parts == vec![1, 2]
+        ));
+        assert!(matches!(
+            parse_arguments(&arguments("run 3 --input -")),
+            Ok(Command::Run(DaySelection::Single(3), options)) if options.input.as_deref() == Some("-")
+        ));
+        assert!(matches!(
+            parse_arguments(&arguments("run 15 --example")),
+            Ok(Command::Run(DaySelection::Single(15), options)) if options.example
+        ));
+    }
+
+    #[test]
+    fn test_parse_verify_arguments() {
+        assert!(matches!(
+            parse_arguments(&arguments("verify")),
+            Ok(Command::Verify(DaySelection::All, options)) if !options.record && !options.example
+        ));
+        assert!(matches!(
+            parse_arguments(&arguments("verify 5 --record")),
+            Ok(Command::Verify(DaySelection::Single(5), options)) if options.record
+        ));
+        assert!(matches!(
+            parse_arguments(&arguments("verify --example")),
+            Ok(Command::Verify(DaySelection::All, options)) if options.example
+        ));
+    }
+
+    #[test]
+    fn test_parse_bench_arguments() {
+        assert!(matches!(
+            parse_arguments(&arguments("bench all")),
+            Ok(Command::Bench(DaySelection::All, options))
+                if options.iterations == 10 && options.output.is_none()
+        ));
+        assert!(matches!(
+            parse_arguments(&arguments("bench 11 -n 3 --output bench.json")),
+            Ok(Command::Bench(DaySelection::Single(11), options))
+                if options.iterations == 3
+                    && matches!(options.output, Some((_, OutputFormat::Json)))
+        ));
+        assert!(matches!(
+            parse_arguments(&arguments("bench all --output results/bench.csv")),
+            Ok(Command::Bench(DaySelection::All, options))
+                if matches!(options.output, Some((_, OutputFormat::Csv)))
+        ));
+    }
+
+    #[test]
+    fn test_parse_new_arguments() {
+        assert!(matches!(
+            parse_arguments(&arguments("new 15 Beacon Exclusion Zone")),
+            Ok(Command::New(15, title)) if title == "Beacon Exclusion Zone"
+        ));
+        assert!(matches!(
+            parse_arguments(&arguments("new 25")),
+            Ok(Command::New(25, title)) if title.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_parse_list_arguments() {
+        assert!(matches!(
+            parse_arguments(&arguments("list")),
+            Ok(Command::List)
+        ));
+    }
+
+    #[test]
+    fn test_parse_invalid_arguments() {
+        assert!(parse_arguments(&arguments("")).is_err());
+        assert!(parse_arguments(&arguments("run")).is_err());
+        assert!(parse_arguments(&arguments("run 99")).is_err());
+        assert!(parse_arguments(&arguments("run 1 --part 3")).is_err());
+        assert!(parse_arguments(&arguments("run 1 --verbose")).is_err());
+        assert!(parse_arguments(&arguments("run 1 --input")).is_err());
+        assert!(parse_arguments(&arguments("run all --input data.txt")).is_err());
+        assert!(parse_arguments(&arguments("verify 99")).is_err());
+        assert!(parse_arguments(&arguments("verify --force")).is_err());
+        assert!(parse_arguments(&arguments("bench")).is_err());
+        assert!(parse_arguments(&arguments("bench 1 --iterations 0")).is_err());
+        assert!(parse_arguments(&arguments("bench 1 --output bench.txt")).is_err());
+        assert!(parse_arguments(&arguments("bench all --input data.txt")).is_err());
+        assert!(parse_arguments(&arguments("new")).is_err());
+        assert!(parse_arguments(&arguments("new 26")).is_err());
+        assert!(parse_arguments(&arguments("jump")).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use aoc2022::cli::exit_with_error;
+use aoc2022::day_15::{BeaconExclusionZone, Sensor};
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+use std::env;
+
+fn print_answers<const ROW: i64, const BOUND: i64>(sensors: &Vec<Sensor>) {
+    // Solution for puzzle 1
+    println!(
+        "The number of positions in row {} where no beacon can be present is {}",
+        ROW,
+        BeaconExclusionZone::<ROW, BOUND>::part1(sensors)
+            .unwrap_or_else(|error| exit_with_error(error))
+    );
+
+    // Solution for puzzle 2
+    println!(
+        "The tuning frequency of the distress beacon is {}",
+        BeaconExclusionZone::<ROW, BOUND>::part2(sensors)
+            .unwrap_or_else(|error| exit_with_error(error))
+    );
+}
+
+fn main() {
+    let raw_input = read_input_from_args(15).unwrap_or_else(|error| exit_with_error(error));
+    let sensors =
+        <BeaconExclusionZone>::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // The example of the puzzle description is checked on a smaller row and square
+    if env::args().any(|argument| argument == "--example") {
+        print_answers::<10, 20>(&sensors);
+    } else {
+        print_answers::<2000000, 4000000>(&sensors);
+    }
+}
@@ -0,0 +1,22 @@
+use aoc2022::cli::exit_with_error;
+use aoc2022::day_16::ProboscideaVolcanium;
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+
+fn main() {
+    let raw_input = read_input_from_args(16).unwrap_or_else(|error| exit_with_error(error));
+    let graph =
+        ProboscideaVolcanium::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // Solution for puzzle 1
+    println!(
+        "The most pressure that can be released in 30 minutes is {}",
+        ProboscideaVolcanium::part1(&graph).unwrap_or_else(|error| exit_with_error(error))
+    );
+
+    // Solution for puzzle 2
+    println!(
+        "The most pressure that can be released with the elephant in 26 minutes is {}",
+        ProboscideaVolcanium::part2(&graph).unwrap_or_else(|error| exit_with_error(error))
+    );
+}
@@ -0,0 +1,22 @@
+use aoc2022::cli::exit_with_error;
+use aoc2022::day_17::{simulate_rocks, PyroclasticFlow};
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+
+fn main() {
+    let raw_input = read_input_from_args(17).unwrap_or_else(|error| exit_with_error(error));
+    let jets = PyroclasticFlow::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // Solution for puzzle 1
+    println!(
+        "The tower is {} units tall after 2022 rocks, its top rows are:\n{}",
+        PyroclasticFlow::part1(&jets).unwrap_or_else(|error| exit_with_error(error)),
+        simulate_rocks(&jets, 2022).render_top_rows(10).trim_end()
+    );
+
+    // Solution for puzzle 2
+    println!(
+        "The tower is {} units tall after 1000000000000 rocks",
+        PyroclasticFlow::part2(&jets).unwrap_or_else(|error| exit_with_error(error))
+    );
+}
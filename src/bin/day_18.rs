@@ -0,0 +1,21 @@
+use aoc2022::cli::exit_with_error;
+use aoc2022::day_18::BoilingBoulders;
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+
+fn main() {
+    let raw_input = read_input_from_args(18).unwrap_or_else(|error| exit_with_error(error));
+    let cubes = BoilingBoulders::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // Solution for puzzle 1
+    println!(
+        "The surface area of the lava droplet is {}",
+        BoilingBoulders::part1(&cubes).unwrap_or_else(|error| exit_with_error(error))
+    );
+
+    // Solution for puzzle 2
+    println!(
+        "The exterior surface area of the lava droplet is {}",
+        BoilingBoulders::part2(&cubes).unwrap_or_else(|error| exit_with_error(error))
+    );
+}
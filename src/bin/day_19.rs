@@ -0,0 +1,22 @@
+use aoc2022::cli::exit_with_error;
+use aoc2022::day_19::NotEnoughMinerals;
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+
+fn main() {
+    let raw_input = read_input_from_args(19).unwrap_or_else(|error| exit_with_error(error));
+    let blueprints =
+        NotEnoughMinerals::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // Solution for puzzle 1
+    println!(
+        "The sum of the quality levels of all blueprints is {}",
+        NotEnoughMinerals::part1(&blueprints).unwrap_or_else(|error| exit_with_error(error))
+    );
+
+    // Solution for puzzle 2
+    println!(
+        "The product of the most geodes of the first three blueprints is {}",
+        NotEnoughMinerals::part2(&blueprints).unwrap_or_else(|error| exit_with_error(error))
+    );
+}
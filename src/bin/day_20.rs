@@ -0,0 +1,22 @@
+use aoc2022::cli::exit_with_error;
+use aoc2022::day_20::GrovePositioningSystem;
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+
+fn main() {
+    let raw_input = read_input_from_args(20).unwrap_or_else(|error| exit_with_error(error));
+    let values =
+        GrovePositioningSystem::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // Solution for puzzle 1
+    println!(
+        "The sum of the grove coordinates is {}",
+        GrovePositioningSystem::part1(&values).unwrap_or_else(|error| exit_with_error(error))
+    );
+
+    // Solution for puzzle 2
+    println!(
+        "The sum of the grove coordinates after decryption is {}",
+        GrovePositioningSystem::part2(&values).unwrap_or_else(|error| exit_with_error(error))
+    );
+}
@@ -0,0 +1,21 @@
+use aoc2022::cli::exit_with_error;
+use aoc2022::day_21::MonkeyMath;
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+
+fn main() {
+    let raw_input = read_input_from_args(21).unwrap_or_else(|error| exit_with_error(error));
+    let graph = MonkeyMath::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // Solution for puzzle 1
+    println!(
+        "The number yelled by the root monkey is {}",
+        MonkeyMath::part1(&graph).unwrap_or_else(|error| exit_with_error(error))
+    );
+
+    // Solution for puzzle 2
+    println!(
+        "The number to yell to pass the equality test is {}",
+        MonkeyMath::part2(&graph).unwrap_or_else(|error| exit_with_error(error))
+    );
+}
@@ -0,0 +1,21 @@
+use aoc2022::cli::exit_with_error;
+use aoc2022::day_22::MonkeyMap;
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+
+fn main() {
+    let raw_input = read_input_from_args(22).unwrap_or_else(|error| exit_with_error(error));
+    let input = MonkeyMap::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // Solution for puzzle 1
+    println!(
+        "The final password on the flat map is {}",
+        MonkeyMap::part1(&input).unwrap_or_else(|error| exit_with_error(error))
+    );
+
+    // Solution for puzzle 2
+    println!(
+        "The final password on the cube is {}",
+        MonkeyMap::part2(&input).unwrap_or_else(|error| exit_with_error(error))
+    );
+}
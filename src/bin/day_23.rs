@@ -0,0 +1,30 @@
+use aoc2022::cli::exit_with_error;
+use aoc2022::day_23::{Diffusion, UnstableDiffusion};
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+use std::env;
+
+fn main() {
+    let raw_input = read_input_from_args(23).unwrap_or_else(|error| exit_with_error(error));
+    let grid = UnstableDiffusion::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // Snapshots of the first ten rounds, printed when AOC_SNAPSHOTS is set
+    if env::var_os("AOC_SNAPSHOTS").is_some() {
+        let mut diffusion = Diffusion::new(grid.clone());
+        for (i, snapshot) in diffusion.run_rounds_with_snapshots(10).iter().enumerate() {
+            println!("== End of Round {} ==\n{}", i + 1, snapshot);
+        }
+    }
+
+    // Solution for puzzle 1
+    println!(
+        "The number of empty ground tiles after 10 rounds is {}",
+        UnstableDiffusion::part1(&grid).unwrap_or_else(|error| exit_with_error(error))
+    );
+
+    // Solution for puzzle 2
+    println!(
+        "The first round where no elf moves is {}",
+        UnstableDiffusion::part2(&grid).unwrap_or_else(|error| exit_with_error(error))
+    );
+}
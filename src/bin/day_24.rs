@@ -0,0 +1,21 @@
+use aoc2022::cli::exit_with_error;
+use aoc2022::day_24::BlizzardBasin;
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+
+fn main() {
+    let raw_input = read_input_from_args(24).unwrap_or_else(|error| exit_with_error(error));
+    let valley = BlizzardBasin::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // Solution for puzzle 1
+    println!(
+        "The fewest minutes to reach the goal is {}",
+        BlizzardBasin::part1(&valley).unwrap_or_else(|error| exit_with_error(error))
+    );
+
+    // Solution for puzzle 2
+    println!(
+        "The fewest minutes to reach the goal, go back to the start and reach the goal again is {}",
+        BlizzardBasin::part2(&valley).unwrap_or_else(|error| exit_with_error(error))
+    );
+}
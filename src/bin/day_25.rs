@@ -0,0 +1,15 @@
+use aoc2022::cli::exit_with_error;
+use aoc2022::day_25::FullOfHotAir;
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+
+fn main() {
+    let raw_input = read_input_from_args(25).unwrap_or_else(|error| exit_with_error(error));
+    let numbers = FullOfHotAir::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // Solution for puzzle 1
+    println!(
+        "The SNAFU number to enter into the console is {}",
+        FullOfHotAir::part1(&numbers).unwrap_or_else(|error| exit_with_error(error))
+    );
+}
@@ -0,0 +1,7 @@
+use std::fmt::Display;
+use std::process;
+
+pub fn exit_with_error(error: impl Display) -> ! {
+    eprintln!("{}", error);
+    process::exit(1);
+}
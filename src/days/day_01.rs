@@ -0,0 +1,117 @@
+use crate::error::{Result, SolveError, SolveResult};
+use crate::parsing::{normalize_input, parse_token};
+use crate::solution::{Answer, Solution};
+
+const DAY: u8 = 1;
+
+pub fn parse_inventory_line(line: &str) -> Result<u32> {
+    parse_token(DAY, line, line.trim(), "a calorie amount")
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<u32>> {
+    let input = &normalize_input(input);
+    let mut elf_inventories = Vec::new();
+    let mut current_inventory = 0;
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            elf_inventories.push(current_inventory);
+            current_inventory = 0;
+        } else {
+            current_inventory += parse_inventory_line(line).map_err(|e| e.shift_lines(i))?;
+        }
+    }
+    elf_inventories.push(current_inventory);
+
+    Ok(elf_inventories)
+}
+
+pub fn sum_first_n(n: usize, elf_inventories: &[u32]) -> Option<u32> {
+    Some(elf_inventories.get(0..n)?.iter().sum())
+}
+
+pub struct CalorieCounting;
+
+impl Solution for CalorieCounting {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(elf_inventories: &Self::Input) -> SolveResult<Answer> {
+        let mut elf_inventories = elf_inventories.clone();
+        elf_inventories.sort();
+        elf_inventories.reverse();
+        match sum_first_n(1, &elf_inventories) {
+            Some(calories) => Ok(calories.into()),
+            None => Err(SolveError::no_answer(DAY, 1, "there are no elves")),
+        }
+    }
+
+    fn part2(elf_inventories: &Self::Input) -> SolveResult<Answer> {
+        let mut elf_inventories = elf_inventories.clone();
+        elf_inventories.sort();
+        elf_inventories.reverse();
+        match sum_first_n(3, &elf_inventories) {
+            Some(calories) => Ok(calories.into()),
+            None => Err(SolveError::no_answer(
+                DAY,
+                2,
+                "there are fewer than 3 elves",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inventory_line_parsing() {
+        assert_eq!(parse_inventory_line("  100"), Ok(100));
+        let error = parse_inventory_line("  1x0").unwrap_err();
+        assert_eq!(error.column, 3);
+        assert_eq!(error.text, "1x0");
+    }
+
+    #[test]
+    fn test_input_parsing() {
+        let input = "200
+        100
+
+        50";
+        let elf_inventories = parse_input(input).unwrap();
+        assert_eq!(elf_inventories, vec![300, 50]);
+    }
+
+    #[test]
+    fn test_input_parsing_error_line() {
+        let error = parse_input("200\n\nabc").unwrap_err();
+        assert_eq!(error.day, 1);
+        assert_eq!(error.line, 3);
+    }
+
+    #[test]
+    fn test_first_calories_sum() {
+        let inventories = vec![2, 1, 4, 3];
+        assert_eq!(sum_first_n(2, &inventories), Some(3));
+        assert_eq!(sum_first_n(5, &inventories), None);
+    }
+
+    #[test]
+    fn test_solution_with_fewer_than_three_elves() {
+        assert_eq!(
+            CalorieCounting::solve_part_1("100\n\n200"),
+            Ok(Answer::Integer(200))
+        );
+        assert!(matches!(
+            CalorieCounting::solve_part_2("100\n\n200"),
+            Err(SolveError::NoAnswer {
+                day: 1,
+                part: 2,
+                ..
+            })
+        ));
+    }
+}
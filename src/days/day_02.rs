@@ -0,0 +1,221 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::parsing::normalize_input;
+use crate::solution::{Answer, Solution};
+
+const DAY: u8 = 2;
+
+pub fn parse_strategy_line(line: &str) -> Result<(char, char)> {
+    let strategy = line.trim();
+    let (opponent_shape, player_shape) = match strategy.chars().collect::<Vec<char>>()[..] {
+        [opponent_shape, ' ', player_shape] => (opponent_shape, player_shape),
+        _ => {
+            return Err(ParseError::new(
+                DAY,
+                line,
+                strategy,
+                "two shapes separated by a space",
+            ))
+        }
+    };
+    if !('A'..='C').contains(&opponent_shape) {
+        let shape = &strategy[..opponent_shape.len_utf8()];
+        return Err(ParseError::new(DAY, line, shape, "`A`, `B` or `C`"));
+    }
+    if !('X'..='Z').contains(&player_shape) {
+        let shape = &strategy[strategy.len() - player_shape.len_utf8()..];
+        return Err(ParseError::new(DAY, line, shape, "`X`, `Y` or `Z`"));
+    }
+    Ok((opponent_shape, player_shape))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(char, char)>> {
+    let input = &normalize_input(input);
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_strategy_line(line).map_err(|e| e.shift_lines(i)))
+        .collect()
+}
+
+pub fn opponent_shape_to_index(shape: &char) -> u32 {
+    u32::from(*shape) - 64 // A is 65
+}
+
+pub fn player_shape_to_index(shape: &char) -> u32 {
+    u32::from(*shape) - 87 // X is 88
+}
+
+pub fn evaluate_game(game_strategy: &(char, char)) -> u32 {
+    let (opponent_shape, player_shape) = game_strategy;
+    let opponent_shape = opponent_shape_to_index(opponent_shape);
+    let player_shape = player_shape_to_index(player_shape);
+    match (opponent_shape, player_shape) {
+        // Victory Combinations
+        (1, 2) | (2, 3) | (3, 1) => 6 + player_shape,
+        // Draw
+        (o, p) if o == p => 3 + p,
+        // Defeat
+        _ => player_shape,
+    }
+}
+
+pub fn evaluate_strategy_guide(strategy_guide: &[(char, char)]) -> u32 {
+    strategy_guide.iter().map(evaluate_game).sum()
+}
+
+pub fn transform_game_outcome_instruction_to_strategy(
+    game_outcome_instruction: &(char, char),
+) -> (char, char) {
+    let (opponent_shape, game_outcome) = game_outcome_instruction;
+    let move_selection_index = (opponent_shape_to_index(opponent_shape) - 1) as usize;
+    let player_shape = match game_outcome {
+        // Victory
+        'Z' => ['Y', 'Z', 'X'][move_selection_index],
+        // Defeat
+        'X' => ['Z', 'X', 'Y'][move_selection_index],
+        // Draw
+        _ => ['X', 'Y', 'Z'][move_selection_index],
+    };
+    (*opponent_shape, player_shape)
+}
+
+pub struct RockPaperScissors;
+
+impl Solution for RockPaperScissors {
+    type Input = Vec<(char, char)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(strategy_guide: &Self::Input) -> SolveResult<Answer> {
+        Ok(evaluate_strategy_guide(strategy_guide).into())
+    }
+
+    fn part2(strategy_guide: &Self::Input) -> SolveResult<Answer> {
+        let strategy_guide: Vec<(char, char)> = strategy_guide
+            .iter()
+            .map(transform_game_outcome_instruction_to_strategy)
+            .collect();
+        Ok(evaluate_strategy_guide(&strategy_guide).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_parsing() {
+        let strategy_guide = parse_input(
+            "A Y
+            C Z",
+        );
+        assert_eq!(strategy_guide, Ok(vec![('A', 'Y'), ('C', 'Z')]));
+    }
+
+    #[test]
+    fn test_input_parsing_error() {
+        let error = parse_input("A X\nB  Y").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.text, "B  Y");
+
+        let error = parse_input("A X\nA Q").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "`X`, `Y` or `Z`");
+
+        let error = parse_input("  D X").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (3, "D"));
+        assert_eq!(error.expected, "`A`, `B` or `C`");
+    }
+
+    #[test]
+    fn test_opponent_shape_to_index() {
+        assert_eq!(opponent_shape_to_index(&'A'), 1);
+        assert_eq!(opponent_shape_to_index(&'B'), 2);
+        assert_eq!(opponent_shape_to_index(&'C'), 3);
+    }
+
+    #[test]
+    fn test_player_shape_to_index() {
+        assert_eq!(player_shape_to_index(&'X'), 1);
+        assert_eq!(player_shape_to_index(&'Y'), 2);
+        assert_eq!(player_shape_to_index(&'Z'), 3);
+    }
+
+    #[test]
+    fn test_game_evaluation_player_victory() {
+        assert_eq!(evaluate_game(&('A', 'Y')), 8);
+        assert_eq!(evaluate_game(&('B', 'Z')), 9);
+        assert_eq!(evaluate_game(&('C', 'X')), 7);
+    }
+
+    #[test]
+    fn test_game_evaluation_player_defeat() {
+        assert_eq!(evaluate_game(&('A', 'Z')), 3);
+        assert_eq!(evaluate_game(&('B', 'X')), 1);
+        assert_eq!(evaluate_game(&('C', 'Y')), 2);
+    }
+
+    #[test]
+    fn test_game_evaluation_draw() {
+        assert_eq!(evaluate_game(&('A', 'X')), 4);
+        assert_eq!(evaluate_game(&('B', 'Y')), 5);
+        assert_eq!(evaluate_game(&('C', 'Z')), 6);
+    }
+
+    #[test]
+    fn test_strategy_guide_evaluation() {
+        let strategy_guide = vec![('A', 'Y'), ('A', 'Z'), ('A', 'X')];
+        let score = evaluate_strategy_guide(&strategy_guide);
+        assert_eq!(score, 15);
+    }
+
+    #[test]
+    fn test_transform_game_outcome_instruction_to_victory_strategy() {
+        assert_eq!(
+            transform_game_outcome_instruction_to_strategy(&('A', 'Z')),
+            ('A', 'Y')
+        );
+        assert_eq!(
+            transform_game_outcome_instruction_to_strategy(&('B', 'Z')),
+            ('B', 'Z')
+        );
+        assert_eq!(
+            transform_game_outcome_instruction_to_strategy(&('C', 'Z')),
+            ('C', 'X')
+        );
+    }
+
+    #[test]
+    fn test_transform_game_outcome_instruction_to_defeat_strategy() {
+        assert_eq!(
+            transform_game_outcome_instruction_to_strategy(&('A', 'X')),
+            ('A', 'Z')
+        );
+        assert_eq!(
+            transform_game_outcome_instruction_to_strategy(&('B', 'X')),
+            ('B', 'X')
+        );
+        assert_eq!(
+            transform_game_outcome_instruction_to_strategy(&('C', 'X')),
+            ('C', 'Y')
+        );
+    }
+
+    #[test]
+    fn test_transform_game_outcome_instruction_to_draw_strategy() {
+        assert_eq!(
+            transform_game_outcome_instruction_to_strategy(&('A', 'Y')),
+            ('A', 'X')
+        );
+        assert_eq!(
+            transform_game_outcome_instruction_to_strategy(&('B', 'Y')),
+            ('B', 'Y')
+        );
+        assert_eq!(
+            transform_game_outcome_instruction_to_strategy(&('C', 'Y')),
+            ('C', 'Z')
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::parsing::normalize_input;
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+const DAY: u8 = 3;
+
+pub fn parse_rucksack_line(line: &str) -> Result<(HashSet<char>, HashSet<char>)> {
+    let rucksack = line.trim();
+    if let Some(i) = rucksack.find(|item: char| !item.is_ascii_alphabetic()) {
+        let invalid_item = &rucksack[i..i + rucksack[i..].chars().next().unwrap().len_utf8()];
+        return Err(ParseError::new(DAY, line, invalid_item, "an item letter"));
+    }
+    let center = rucksack.len() / 2;
+    let compartments = (
+        get_item_set(&rucksack[..center]),
+        get_item_set(&rucksack[center..]),
+    );
+    if compartments.0.is_disjoint(&compartments.1) {
+        return Err(ParseError::new(
+            DAY,
+            line,
+            rucksack,
+            "a rucksack with an item type in both compartments",
+        ));
+    }
+    Ok(compartments)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(HashSet<char>, HashSet<char>)>> {
+    let input = &normalize_input(input);
+    let rucksack_compartments = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_rucksack_line(line).map_err(|e| e.shift_lines(i)))
+        .collect::<Result<Vec<(HashSet<char>, HashSet<char>)>>>()?;
+
+    // Every group of three elves needs a badge, the error points to the group's last rucksack
+    let lines = input.lines().collect::<Vec<&str>>();
+    for (group_index, group) in group_elf_rucksacks(&rucksack_compartments)
+        .iter()
+        .enumerate()
+    {
+        if find_badge_of_elf_group(group).is_none() {
+            let line_index = group_index * 3 + group.len() - 1;
+            let line = lines[line_index];
+            return Err(ParseError::new(
+                DAY,
+                line,
+                line.trim(),
+                "a rucksack sharing an item type with the other rucksacks of its group",
+            )
+            .shift_lines(line_index));
+        }
+    }
+    Ok(rucksack_compartments)
+}
+
+pub fn get_item_set(all_items: &str) -> HashSet<char> {
+    all_items.chars().collect()
+}
+
+pub fn get_common_item(rucksack_compartments: &[HashSet<char>]) -> Option<char> {
+    let mut common_items = rucksack_compartments[0].clone();
+    for compartment in rucksack_compartments[1..].iter() {
+        common_items = common_items.intersection(compartment).cloned().collect();
+    }
+    common_items.iter().next().copied()
+}
+
+pub fn get_item_priority(item: char) -> u32 {
+    if item.is_lowercase() {
+        u32::from(item) - 96 // a is 97
+    } else {
+        u32::from(item) - 38 // A is 65 (65-27=38)
+    }
+}
+
+pub fn get_common_item_priorities_sum(
+    rucksack_compartments: &[(HashSet<char>, HashSet<char>)],
+) -> u32 {
+    rucksack_compartments
+        .iter()
+        .cloned()
+        .map(|(compartment_1, compartment_2)| {
+            get_common_item(&[compartment_1, compartment_2])
+                .expect("the parser checks that both compartments share an item")
+        })
+        .map(get_item_priority)
+        .sum()
+}
+
+pub fn group_elf_rucksacks(
+    rucksacks: &[(HashSet<char>, HashSet<char>)],
+) -> Vec<Vec<HashSet<char>>> {
+    let mut grouped_rucksacks = Vec::new();
+    for elves_group in rucksacks.chunks(3) {
+        let elves_group_rucksacks = elves_group
+            .iter()
+            .map(|(compartment_1, compartment_2)| {
+                compartment_1.union(compartment_2).cloned().collect()
+            })
+            .collect::<Vec<HashSet<char>>>();
+        grouped_rucksacks.push(elves_group_rucksacks);
+    }
+    grouped_rucksacks
+}
+
+pub fn find_badge_of_elf_group(group_rucksacks: &[HashSet<char>]) -> Option<char> {
+    get_common_item(group_rucksacks)
+}
+
+pub struct RucksackReorganization;
+
+impl Solution for RucksackReorganization {
+    type Input = Vec<(HashSet<char>, HashSet<char>)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(rucksack_compartments: &Self::Input) -> SolveResult<Answer> {
+        Ok(get_common_item_priorities_sum(rucksack_compartments).into())
+    }
+
+    fn part2(rucksack_compartments: &Self::Input) -> SolveResult<Answer> {
+        Ok(group_elf_rucksacks(rucksack_compartments)
+            .iter()
+            .map(|group| {
+                find_badge_of_elf_group(group)
+                    .expect("the parser checks that every group has a badge")
+            })
+            .map(get_item_priority)
+            .sum::<u32>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_parsing() {
+        let rucksack_compartments = parse_input(
+            "abCa
+            aFGhaj",
+        )
+        .unwrap();
+        assert_eq!(
+            rucksack_compartments,
+            vec![
+                (HashSet::from(['a', 'b']), HashSet::from(['C', 'a'])),
+                (
+                    HashSet::from(['a', 'F', 'G']),
+                    HashSet::from(['h', 'a', 'j'])
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_parsing_error() {
+        let error = parse_input("abCa\nab1D").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 3);
+        assert_eq!(error.text, "1");
+
+        let error = parse_input("abCa\nabCD").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (2, "abCD"));
+        assert_eq!(
+            error.expected,
+            "a rucksack with an item type in both compartments"
+        );
+
+        let error = parse_input("aa\nbb\ncc\naa\naa\naa\nbb").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (3, "cc"));
+        assert_eq!(
+            error.expected,
+            "a rucksack sharing an item type with the other rucksacks of its group"
+        );
+    }
+
+    #[test]
+    fn test_common_compartment_item() {
+        assert_eq!(
+            get_common_item(&[HashSet::from(['a']), HashSet::from(['a'])]),
+            Some('a')
+        );
+        assert_eq!(
+            get_common_item(&[HashSet::from(['a', 'b']), HashSet::from(['B', 'a'])]),
+            Some('a')
+        );
+        assert_eq!(
+            get_common_item(&[HashSet::from(['a']), HashSet::from(['b'])]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_item_priorities() {
+        assert_eq!(get_item_priority('a'), 1);
+        assert_eq!(get_item_priority('z'), 26);
+        assert_eq!(get_item_priority('A'), 27);
+        assert_eq!(get_item_priority('Z'), 52);
+    }
+
+    #[test]
+    fn test_common_item_priorities_sum() {
+        let rucksack_compartments = vec![
+            (HashSet::from(['a']), HashSet::from(['a'])),
+            (HashSet::from(['A', 'b']), HashSet::from(['B', 'A'])),
+        ];
+        assert_eq!(get_common_item_priorities_sum(&rucksack_compartments), 28);
+    }
+
+    #[test]
+    fn test_elf_rucksacks_grouping() {
+        let rucksack_compartments = vec![
+            (HashSet::from(['a']), HashSet::from(['A'])),
+            (HashSet::from(['b']), HashSet::from(['B'])),
+            (HashSet::from(['c']), HashSet::from(['C'])),
+            (HashSet::from(['d']), HashSet::from(['D'])),
+            (HashSet::from(['e']), HashSet::from(['E'])),
+            (HashSet::from(['f']), HashSet::from(['F'])),
+        ];
+        assert_eq!(
+            group_elf_rucksacks(&rucksack_compartments),
+            vec![
+                vec![
+                    HashSet::from(['a', 'A']),
+                    HashSet::from(['b', 'B']),
+                    HashSet::from(['c', 'C']),
+                ],
+                vec![
+                    HashSet::from(['d', 'D']),
+                    HashSet::from(['e', 'E']),
+                    HashSet::from(['f', 'F']),
+                ]
+            ]
+        )
+    }
+}
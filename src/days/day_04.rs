@@ -0,0 +1,129 @@
+use crate::error::{Result, SolveResult};
+use crate::parsing::{normalize_input, parse_lines, LineParser};
+use crate::solution::{Answer, Solution};
+use std::ops::RangeInclusive;
+
+const DAY: u8 = 4;
+
+// A line has the format `<start>-<end>,<start>-<end>`
+pub fn parse_section_range(parser: &mut LineParser) -> Result<RangeInclusive<u32>> {
+    let section_start: u32 = parser.unsigned("a section id")?;
+    parser.literal("-")?;
+    let (section_end, section_end_text) =
+        parser.consumed(|parser| parser.unsigned::<u32>("a section id"))?;
+    if section_end < section_start {
+        return Err(parser.error(
+            section_end_text,
+            &format!("a section id of at least {}", section_start),
+        ));
+    }
+    Ok(section_start..=section_end)
+}
+
+pub fn parse_section_assignment_pair(
+    parser: &mut LineParser,
+) -> Result<(RangeInclusive<u32>, RangeInclusive<u32>)> {
+    let sections_1 = parse_section_range(parser)?;
+    parser.literal(",")?;
+    let sections_2 = parse_section_range(parser)?;
+    Ok((sections_1, sections_2))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>> {
+    let input = &normalize_input(input);
+    parse_lines(DAY, input, parse_section_assignment_pair)
+}
+
+fn contains_range(outer: &RangeInclusive<u32>, inner: &RangeInclusive<u32>) -> bool {
+    outer.start() <= inner.start() && inner.end() <= outer.end()
+}
+
+pub fn count_fully_contained_sections(
+    cleaning_section: &[(RangeInclusive<u32>, RangeInclusive<u32>)],
+) -> usize {
+    cleaning_section
+        .iter()
+        .filter(|(section_1, section_2)| {
+            contains_range(section_2, section_1) || contains_range(section_1, section_2)
+        })
+        .count()
+}
+
+pub fn count_intersecting_sections(
+    cleaning_section: &[(RangeInclusive<u32>, RangeInclusive<u32>)],
+) -> usize {
+    cleaning_section
+        .iter()
+        .filter(|(section_1, section_2)| {
+            section_1.start() <= section_2.end() && section_2.start() <= section_1.end()
+        })
+        .count()
+}
+
+pub struct CampCleanup;
+
+impl Solution for CampCleanup {
+    type Input = Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(cleaning_sections: &Self::Input) -> SolveResult<Answer> {
+        Ok(count_fully_contained_sections(cleaning_sections).into())
+    }
+
+    fn part2(cleaning_sections: &Self::Input) -> SolveResult<Answer> {
+        Ok(count_intersecting_sections(cleaning_sections).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_parsing() {
+        let cleaning_sections = parse_input(
+            "1-2,2-3
+            4-6,5-7",
+        )
+        .unwrap();
+        assert_eq!(cleaning_sections, vec![(1..=2, 2..=3), (4..=6, 5..=7)]);
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("1-2,2-3\n1-2;2-3").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.text, ";2-3");
+        assert_eq!(error.expected, "`,`");
+
+        let error = parse_input("1-2,2-x").unwrap_err();
+        assert_eq!(error.column, 7);
+        assert_eq!(error.text, "x");
+
+        let error = parse_input("1-2,2-3\n2-3,5-1").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 7);
+        assert_eq!(error.expected, "a section id of at least 5");
+    }
+
+    #[test]
+    fn test_large_section_ranges() {
+        let cleaning_sections = parse_input("1-4000000000,2-3").unwrap();
+        assert_eq!(count_fully_contained_sections(&cleaning_sections), 1);
+    }
+
+    #[test]
+    fn test_count_fully_contained_sections() {
+        let cleaning_sections = vec![(1..=2, 3..=4), (5..=8, 6..=7), (9..=11, 10..=10)];
+        assert_eq!(count_fully_contained_sections(&cleaning_sections), 2);
+    }
+
+    #[test]
+    fn test_count_intersecting_sections() {
+        let cleaning_sections = vec![(1..=2, 2..=3), (5..=8, 6..=7), (9..=9, 10..=10)];
+        assert_eq!(count_intersecting_sections(&cleaning_sections), 2);
+    }
+}
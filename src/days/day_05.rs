@@ -0,0 +1,344 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::parsing::{
+    end_of_line, normalize_input, parse_line, parse_token, BlockParser, LineParser,
+};
+use crate::solution::{Answer, Solution};
+
+const DAY: u8 = 5;
+
+#[derive(Clone, Debug)]
+pub struct CargoStacks {
+    pub stacks: Vec<Vec<char>>,
+}
+
+impl CargoStacks {
+    pub fn new(initial_stacks: &str) -> Result<Self> {
+        let initial_stacks_lines = initial_stacks.lines().collect::<Vec<&str>>();
+        let number_of_lines = initial_stacks_lines.len();
+        let initial_stack_composition = initial_stacks_lines
+            .iter()
+            .take(number_of_lines.saturating_sub(1));
+        let stack_numbers_line = initial_stacks_lines.last().unwrap_or(&initial_stacks);
+        let stacks_number = parse_token::<usize>(
+            DAY,
+            stack_numbers_line,
+            stack_numbers_line
+                .split_whitespace()
+                .last()
+                .unwrap_or(end_of_line(stack_numbers_line)),
+            "the number of stacks",
+        )
+        .map_err(|e| e.shift_lines(number_of_lines.saturating_sub(1)))?;
+        let mut stacks = vec![Vec::new(); stacks_number];
+
+        for (i, stack_layer) in initial_stack_composition.enumerate() {
+            for (position, cargo_crate) in stack_layer.char_indices().filter(|(p, _)| p % 4 == 1) {
+                if !cargo_crate.is_ascii_alphabetic() {
+                    continue;
+                }
+                let stack_index = position / 4;
+                if stack_index >= stacks_number {
+                    return Err(ParseError::new(
+                        DAY,
+                        stack_layer,
+                        &stack_layer[position..position + 1],
+                        "a crate on one of the numbered stacks",
+                    )
+                    .shift_lines(i));
+                }
+                stacks[stack_index].insert(0, cargo_crate);
+            }
+        }
+
+        Ok(Self { stacks })
+    }
+
+    pub fn execute_movement_command_single_crate(&mut self, command: &MovementCommand) {
+        for _ in 0..command.amount {
+            let cargo_crate = self.stacks[command.from - 1].pop().unwrap();
+            self.stacks[command.to - 1].push(cargo_crate);
+        }
+    }
+
+    pub fn execute_movement_command_multi_crate(&mut self, command: &MovementCommand) {
+        let source_stack = self.stacks[command.from - 1].clone();
+        self.stacks[command.to - 1]
+            .extend_from_slice(&source_stack[(source_stack.len() - command.amount)..]);
+        self.stacks[command.from - 1].truncate(source_stack.len() - command.amount);
+    }
+
+    pub fn get_top_crates(&self) -> Vec<char> {
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .cloned()
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct MovementCommand {
+    pub from: usize,
+    pub to: usize,
+    pub amount: usize,
+}
+
+impl MovementCommand {
+    pub fn new(command_line: &str) -> Result<Self> {
+        parse_line(DAY, command_line, Self::parse)
+    }
+
+    // A command has the format `move <amount> from <stack> to <stack>`
+    pub fn parse(parser: &mut LineParser) -> Result<Self> {
+        parser.literal("move")?;
+        let amount = parser.unsigned("a number")?;
+        parser.literal("from")?;
+        let from = parser.unsigned("a number")?;
+        parser.literal("to")?;
+        let to = parser.unsigned("a number")?;
+
+        Ok(Self { from, to, amount })
+    }
+}
+
+// Checks a command against the current heights of the stacks and applies it to them, both crane
+// models change the heights in the same way
+fn check_movement_command(
+    command: &MovementCommand,
+    stack_heights: &mut [usize],
+) -> std::result::Result<(), String> {
+    let stacks_number = stack_heights.len();
+    if ![command.from, command.to]
+        .iter()
+        .all(|stack| (1..=stacks_number).contains(stack))
+    {
+        return Err(format!("a move between the stacks 1 to {}", stacks_number));
+    }
+    let source_height = stack_heights[command.from - 1];
+    if command.amount > source_height {
+        return Err(format!(
+            "a move of at most {} crates from stack {}",
+            source_height, command.from
+        ));
+    }
+    stack_heights[command.from - 1] -= command.amount;
+    stack_heights[command.to - 1] += command.amount;
+    Ok(())
+}
+
+pub fn parse_input(input: &str) -> Result<(CargoStacks, Vec<MovementCommand>)> {
+    let input = &normalize_input(input);
+    let (initial_stack_composition, movement_commands) = match input.split_once("\n\n") {
+        Some(sections) => sections,
+        None => {
+            let last_line = input.lines().last().unwrap_or(input);
+            return Err(ParseError::new(
+                DAY,
+                last_line,
+                end_of_line(last_line),
+                "an empty line followed by movement commands",
+            )
+            .shift_lines(input.lines().count().saturating_sub(1)));
+        }
+    };
+    let initial_stack_composition = CargoStacks::new(initial_stack_composition)?;
+    let line_offset = input.lines().count() - movement_commands.lines().count();
+    let mut stack_heights = initial_stack_composition
+        .stacks
+        .iter()
+        .map(Vec::len)
+        .collect::<Vec<usize>>();
+    let movement_commands = BlockParser::new(DAY, movement_commands, line_offset)
+        .parse_remaining_lines(|parser| {
+            let (command, command_text) = parser.consumed(MovementCommand::parse)?;
+            check_movement_command(&command, &mut stack_heights)
+                .map_err(|expected| parser.error(command_text, &expected))?;
+            Ok(command)
+        })?;
+    Ok((initial_stack_composition, movement_commands))
+}
+
+pub struct SupplyStacks;
+
+impl Solution for SupplyStacks {
+    type Input = (CargoStacks, Vec<MovementCommand>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(stacks_and_commands: &Self::Input) -> SolveResult<Answer> {
+        let (stack_composition, movement_commands) = stacks_and_commands;
+        let mut stack_composition = stack_composition.clone();
+        movement_commands
+            .iter()
+            .for_each(|command| stack_composition.execute_movement_command_single_crate(command));
+        Ok(stack_composition
+            .get_top_crates()
+            .iter()
+            .collect::<String>()
+            .into())
+    }
+
+    fn part2(stacks_and_commands: &Self::Input) -> SolveResult<Answer> {
+        let (stack_composition, movement_commands) = stacks_and_commands;
+        let mut stack_composition = stack_composition.clone();
+        movement_commands
+            .iter()
+            .for_each(|command| stack_composition.execute_movement_command_multi_crate(command));
+        Ok(stack_composition
+            .get_top_crates()
+            .iter()
+            .collect::<String>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_movement_command_from_command_text() {
+        let movement_command = MovementCommand::new("move 1 from 22 to 333").unwrap();
+        assert_eq!(movement_command.amount, 1);
+        assert_eq!(movement_command.from, 22);
+        assert_eq!(movement_command.to, 333);
+    }
+
+    #[test]
+    fn test_movement_command_errors() {
+        let error = MovementCommand::new("move 1 from x to 3").unwrap_err();
+        assert_eq!(error.column, 13);
+        assert_eq!(error.expected, "a number");
+
+        let error = MovementCommand::new("move 1 to 3").unwrap_err();
+        assert_eq!(error.text, "to");
+        assert_eq!(error.expected, "`from`");
+
+        let error = MovementCommand::new("move 1 from 2").unwrap_err();
+        assert_eq!(error.column, 14);
+        assert_eq!(error.text, "");
+    }
+
+    #[test]
+    fn test_cargo_stack_from_text() {
+        let stack_configuration = "    [A]    \n[B] [C] [D]\n 1   2   3 ";
+        let cargo_stacks = CargoStacks::new(stack_configuration).unwrap();
+        assert_eq!(
+            cargo_stacks.stacks,
+            vec![vec!['B'], vec!['C', 'A'], vec!['D']]
+        )
+    }
+
+    #[test]
+    fn test_input_parsing() {
+        let input =
+            "    [A]    \n[B] [C] [D]\n 1   2   3 \n\nmove 1 from 2 to 3\nmove 2 from 3 to 1";
+        let (initial_stack_composition, movement_commands) = parse_input(input).unwrap();
+        assert_eq!(
+            initial_stack_composition.stacks,
+            vec![vec!['B'], vec!['C', 'A'], vec!['D']]
+        );
+        assert_eq!(movement_commands[0].amount, 1);
+        assert_eq!(movement_commands[0].from, 2);
+        assert_eq!(movement_commands[0].to, 3);
+        assert_eq!(movement_commands[1].amount, 2);
+        assert_eq!(movement_commands[1].from, 3);
+        assert_eq!(movement_commands[1].to, 1);
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = CargoStacks::new("    [A]     [E]\n[B] [C] [D]\n 1   2   3 ").unwrap_err();
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 14);
+
+        let error = parse_input("[A]\n 1 \nmove 1 from 1 to 1").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(
+            error.expected,
+            "an empty line followed by movement commands"
+        );
+
+        let error = parse_input("[A]\n 1 \n\nmove 1 from 1 to 1\nmove 1 frm 1 to 1").unwrap_err();
+        assert_eq!(error.line, 5);
+        assert_eq!(error.text, "frm");
+
+        let error = parse_input("[A]\n 1 \n\nmove 1 from 2 to 1").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.text, "move 1 from 2 to 1");
+        assert_eq!(error.expected, "a move between the stacks 1 to 1");
+
+        let error = parse_input("[A]\n 1 \n\nmove 1 from 1 to 0").unwrap_err();
+        assert_eq!(error.expected, "a move between the stacks 1 to 1");
+
+        let error =
+            parse_input("[A]    \n[B] [C]\n 1   2 \n\nmove 1 from 2 to 1\nmove 4 from 1 to 2")
+                .unwrap_err();
+        assert_eq!(error.line, 6);
+        assert_eq!(error.expected, "a move of at most 3 crates from stack 1");
+    }
+
+    #[test]
+    fn test_movement_command_single_crate_execution() {
+        let mut cargo_stacks = CargoStacks {
+            stacks: vec![vec!['B'], vec!['C', 'A'], vec!['D']],
+        };
+        let single_crate_command = MovementCommand {
+            amount: 1,
+            from: 1,
+            to: 3,
+        };
+        cargo_stacks.execute_movement_command_single_crate(&single_crate_command);
+        assert_eq!(
+            cargo_stacks.stacks,
+            vec![vec![], vec!['C', 'A'], vec!['D', 'B']]
+        );
+        let multi_crate_command = MovementCommand {
+            amount: 2,
+            from: 2,
+            to: 3,
+        };
+        cargo_stacks.execute_movement_command_single_crate(&multi_crate_command);
+        assert_eq!(
+            cargo_stacks.stacks,
+            vec![vec![], vec![], vec!['D', 'B', 'A', 'C']]
+        );
+    }
+
+    #[test]
+    fn test_movement_command_multi_crate_execution() {
+        let mut cargo_stacks = CargoStacks {
+            stacks: vec![vec!['B'], vec!['C', 'A'], vec!['D']],
+        };
+        let single_crate_command = MovementCommand {
+            amount: 1,
+            from: 1,
+            to: 3,
+        };
+        cargo_stacks.execute_movement_command_multi_crate(&single_crate_command);
+        assert_eq!(
+            cargo_stacks.stacks,
+            vec![vec![], vec!['C', 'A'], vec!['D', 'B']]
+        );
+        let multi_crate_command = MovementCommand {
+            amount: 2,
+            from: 2,
+            to: 3,
+        };
+        cargo_stacks.execute_movement_command_multi_crate(&multi_crate_command);
+        assert_eq!(
+            cargo_stacks.stacks,
+            vec![vec![], vec![], vec!['D', 'B', 'C', 'A']]
+        );
+    }
+
+    #[test]
+    fn test_get_top_crates() {
+        let cargo_stacks = CargoStacks {
+            stacks: vec![vec!['B'], vec!['C', 'A'], vec!['D']],
+        };
+        assert_eq!(cargo_stacks.get_top_crates(), vec!['B', 'A', 'D']);
+    }
+}
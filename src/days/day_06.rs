@@ -0,0 +1,148 @@
+use crate::error::{ParseError, Result, SolveError, SolveResult};
+use crate::parsing::normalize_input;
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+const DAY: u8 = 6;
+
+pub fn parse_input(input: &str) -> Result<Vec<(usize, char)>> {
+    let input = &normalize_input(input);
+    let datastream = input.trim();
+    if let Some((i, line)) = datastream.lines().enumerate().nth(1) {
+        return Err(
+            ParseError::new(DAY, line, line, "the end of the single line datastream")
+                .shift_lines(i),
+        );
+    }
+    Ok(datastream
+        .chars()
+        .enumerate()
+        .map(|(n, c)| (n + 1, c))
+        .collect())
+}
+
+pub fn find_start_marker(
+    signal_datastream: &[(usize, char)],
+    marker_size: usize,
+) -> Option<&[(usize, char)]> {
+    signal_datastream.windows(marker_size).find(|candidate| {
+        candidate
+            .iter()
+            .map(|(_, c)| *c)
+            .collect::<HashSet<char>>()
+            .len()
+            == marker_size
+    })
+}
+
+fn get_marker_answer(
+    signal_datastream: &[(usize, char)],
+    marker_size: usize,
+    part: usize,
+) -> SolveResult<Answer> {
+    match find_start_marker(signal_datastream, marker_size) {
+        Some(marker) => Ok(marker[marker_size - 1].0.into()),
+        None => Err(SolveError::no_answer(
+            DAY,
+            part,
+            &format!("no {} characters in a row are all different", marker_size),
+        )),
+    }
+}
+
+pub struct TuningTrouble;
+
+impl Solution for TuningTrouble {
+    type Input = Vec<(usize, char)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(signal_datastream: &Self::Input) -> SolveResult<Answer> {
+        get_marker_answer(signal_datastream, 4, 1)
+    }
+
+    fn part2(signal_datastream: &Self::Input) -> SolveResult<Answer> {
+        get_marker_answer(signal_datastream, 14, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_parsing() {
+        assert_eq!(parse_input("aBc"), Ok(vec![(1, 'a'), (2, 'B'), (3, 'c')]));
+    }
+
+    #[test]
+    fn test_input_parsing_error() {
+        let error = parse_input("abc\ndef\n").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.text, "def");
+    }
+
+    #[test]
+    fn test_find_start_of_packet_marker() {
+        let test_subjects = vec![
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
+        ];
+
+        for (data, expected_result) in test_subjects {
+            let datastream = parse_input(data).unwrap();
+            let start_of_packet_marker = find_start_marker(&datastream, 4);
+            assert_eq!(
+                start_of_packet_marker.unwrap().last().unwrap().0,
+                expected_result
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_start_of_message_marker() {
+        let test_subjects = vec![
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
+        ];
+
+        for (data, expected_result) in test_subjects {
+            let datastream = parse_input(data).unwrap();
+            let start_of_packet_marker = find_start_marker(&datastream, 14);
+            assert_eq!(
+                start_of_packet_marker.unwrap().last().unwrap().0,
+                expected_result
+            );
+        }
+    }
+
+    #[test]
+    fn test_datastream_without_marker() {
+        assert_eq!(find_start_marker(&parse_input("aaaa").unwrap(), 4), None);
+        for input in ["", "aaaa"] {
+            assert!(matches!(
+                TuningTrouble::solve_part_1(input),
+                Err(SolveError::NoAnswer {
+                    day: 6,
+                    part: 1,
+                    ..
+                })
+            ));
+        }
+        assert_eq!(TuningTrouble::solve_part_1("abcd"), Ok(Answer::Integer(4)));
+        assert!(matches!(
+            TuningTrouble::solve_part_2("abcd"),
+            Err(SolveError::NoAnswer {
+                day: 6,
+                part: 2,
+                ..
+            })
+        ));
+    }
+}
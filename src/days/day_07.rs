@@ -0,0 +1,355 @@
+use crate::error::{ParseError, Result, SolveError, SolveResult};
+use crate::parsing::{end_of_line, normalize_input, parse_token};
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+const DAY: u8 = 7;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ShellCommand {
+    Cd(CdDirection),
+    Ls(Vec<DirChild>),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum CdDirection {
+    Root,
+    Parent,
+    Child(String),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum DirChild {
+    Dir(String),
+    File(u64, String),
+}
+
+fn get_shell_line_token<'a>(
+    shell_line: &'a str,
+    shell_line_tokens: &[&'a str],
+    index: usize,
+    expected: &str,
+) -> Result<&'a str> {
+    shell_line_tokens
+        .get(index)
+        .cloned()
+        .ok_or_else(|| ParseError::new(DAY, shell_line, end_of_line(shell_line), expected))
+}
+
+fn parse_cd_direction(shell_line: &str, shell_line_tokens: &[&str]) -> Result<Option<CdDirection>> {
+    match get_shell_line_token(shell_line, shell_line_tokens, 1, "a command")? {
+        "cd" => {
+            let cd_direction =
+                match get_shell_line_token(shell_line, shell_line_tokens, 2, "a directory")? {
+                    "/" => CdDirection::Root,
+                    ".." => CdDirection::Parent,
+                    c => CdDirection::Child(String::from(c)),
+                };
+            Ok(Some(cd_direction))
+        }
+        "ls" => Ok(None),
+        command => Err(ParseError::new(DAY, shell_line, command, "`cd` or `ls`")),
+    }
+}
+
+fn parse_dir_child(shell_line: &str, shell_line_tokens: &[&str]) -> Result<DirChild> {
+    let child_name =
+        get_shell_line_token(shell_line, shell_line_tokens, 1, "a directory entry name")?;
+    if shell_line_tokens[0] == "dir" {
+        Ok(DirChild::Dir(String::from(child_name)))
+    } else {
+        Ok(DirChild::File(
+            parse_token(
+                DAY,
+                shell_line,
+                shell_line_tokens[0],
+                "`dir` or a file size",
+            )?,
+            String::from(child_name),
+        ))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<ShellCommand>> {
+    let input = &normalize_input(input);
+    let mut shell_commands = Vec::new();
+    let mut ls_results = None;
+    let mut current_depth = 0;
+    for (i, shell_line) in input.lines().enumerate() {
+        let shell_line_tokens = shell_line.split_whitespace().collect::<Vec<&str>>();
+        let first_token = get_shell_line_token(shell_line, &shell_line_tokens, 0, "a shell line")
+            .map_err(|e| e.shift_lines(i))?;
+        if first_token == "$" {
+            if let Some(dir_children) = ls_results {
+                // Previous list of directory children after ls command ended and can be collected
+                shell_commands.push(ShellCommand::Ls(dir_children));
+                ls_results = None;
+            }
+
+            // cd command can be added without data from subsequent lines
+            if let Some(cd_direction) =
+                parse_cd_direction(shell_line, &shell_line_tokens).map_err(|e| e.shift_lines(i))?
+            {
+                match cd_direction {
+                    CdDirection::Root => current_depth = 0,
+                    CdDirection::Parent if current_depth == 0 => {
+                        return Err(ParseError::new(
+                            DAY,
+                            shell_line,
+                            shell_line_tokens[2],
+                            "`/` or a directory name while at the root",
+                        )
+                        .shift_lines(i));
+                    }
+                    CdDirection::Parent => current_depth -= 1,
+                    CdDirection::Child(_) => current_depth += 1,
+                }
+                shell_commands.push(ShellCommand::Cd(cd_direction));
+            }
+        } else {
+            // Lines without a leading $ are directory elements after an ls command
+            let current_dir_child =
+                parse_dir_child(shell_line, &shell_line_tokens).map_err(|e| e.shift_lines(i))?;
+            ls_results = if let Some(mut dir_children) = ls_results {
+                dir_children.push(current_dir_child);
+                Some(dir_children)
+            } else {
+                Some(vec![current_dir_child])
+            };
+        }
+    }
+
+    if let Some(dir_children) = ls_results {
+        // Previous list of directory children after ls command ended and can be collected
+        shell_commands.push(ShellCommand::Ls(dir_children));
+    }
+
+    Ok(shell_commands)
+}
+
+pub fn get_all_partial_paths_from_path_elements(path_elements: Vec<&str>) -> Vec<String> {
+    let mut path_directories = vec![String::from("/")];
+
+    if !path_elements.is_empty() {
+        for i in 0..path_elements.len() {
+            let mut path = String::from('/');
+            path.push_str(path_elements[0..=i].join("/").as_str());
+            path_directories.push(path);
+        }
+    }
+
+    path_directories
+}
+
+pub fn get_size_sum_of_directory_children(dir_children: &[DirChild]) -> u64 {
+    dir_children
+        .iter()
+        .map(|dir_child| match dir_child {
+            DirChild::File(size, _) => *size,
+            _ => 0,
+        })
+        .sum()
+}
+
+pub fn aggregate_directory_sizes_from_shell_lines(
+    shell_lines: &[ShellCommand],
+) -> HashMap<String, u64> {
+    let mut directory_sizes = HashMap::new();
+    let mut current_path = Vec::new();
+    for shell_line in shell_lines {
+        match shell_line {
+            ShellCommand::Cd(direction) => {
+                match direction {
+                    CdDirection::Root => {
+                        current_path = Vec::new();
+                    }
+                    CdDirection::Parent => {
+                        current_path.pop();
+                    }
+                    CdDirection::Child(child_dir) => {
+                        current_path.push(child_dir.as_str());
+                    }
+                };
+            }
+            ShellCommand::Ls(dir_children) => {
+                let child_files_size_sum = get_size_sum_of_directory_children(dir_children);
+
+                let paths = get_all_partial_paths_from_path_elements(current_path.clone());
+
+                for path in paths {
+                    if let Some(dir_size) = directory_sizes.get(&path) {
+                        directory_sizes.insert(path, dir_size + child_files_size_sum);
+                    } else {
+                        directory_sizes.insert(path, child_files_size_sum);
+                    }
+                }
+            }
+        };
+    }
+
+    directory_sizes
+}
+
+pub struct NoSpaceLeftOnDevice;
+
+impl Solution for NoSpaceLeftOnDevice {
+    type Input = Vec<ShellCommand>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(shell_commands: &Self::Input) -> SolveResult<Answer> {
+        let directories = aggregate_directory_sizes_from_shell_lines(shell_commands);
+        Ok(directories
+            .values()
+            .cloned()
+            .filter(|size| *size <= 100000)
+            .sum::<u64>()
+            .into())
+    }
+
+    fn part2(shell_commands: &Self::Input) -> SolveResult<Answer> {
+        let directories = aggregate_directory_sizes_from_shell_lines(shell_commands);
+        let used_space = match directories.get("/") {
+            Some(used_space) => *used_space,
+            None => return Err(SolveError::no_answer(DAY, 2, "no directory is listed")),
+        };
+        // Deleting the root directory always frees enough space, so there is a smallest candidate
+        let missing_space = used_space.saturating_sub(70000000 - 30000000);
+        Ok(directories
+            .values()
+            .cloned()
+            .filter(|size| *size >= missing_space)
+            .min()
+            .unwrap_or(used_space)
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_parsing() {
+        let input = "$ cd /
+        $ cd a
+        $ cd ..
+        $ cd a
+        $ ls
+        dir abc
+        123 test.txt
+        $ cd /
+        $ ls
+        dir def";
+
+        let shell_command = parse_input(input).unwrap();
+        assert_eq!(
+            shell_command,
+            vec![
+                ShellCommand::Cd(CdDirection::Root),
+                ShellCommand::Cd(CdDirection::Child(String::from("a"))),
+                ShellCommand::Cd(CdDirection::Parent),
+                ShellCommand::Cd(CdDirection::Child(String::from("a"))),
+                ShellCommand::Ls(vec![
+                    DirChild::Dir(String::from("abc")),
+                    DirChild::File(123, String::from("test.txt")),
+                ]),
+                ShellCommand::Cd(CdDirection::Root),
+                ShellCommand::Ls(vec![DirChild::Dir(String::from("def"))]),
+            ]
+        )
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("$ cd /\n$ rm a").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.text, "rm");
+
+        let error = parse_input("$ ls\n12a b.txt").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.expected, "`dir` or a file size");
+
+        let error = parse_input("$ cd").unwrap_err();
+        assert_eq!(error.column, 5);
+        assert_eq!(error.expected, "a directory");
+
+        let error = parse_input("$ cd a\n$ cd ..\n$ cd ..\n$ ls\n1 a").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 6);
+        assert_eq!(error.text, "..");
+    }
+
+    #[test]
+    fn test_solution_without_directories() {
+        assert_eq!(
+            NoSpaceLeftOnDevice::solve_part_1(""),
+            Ok(Answer::Integer(0))
+        );
+        assert!(matches!(
+            NoSpaceLeftOnDevice::solve_part_2(""),
+            Err(SolveError::NoAnswer {
+                day: 7,
+                part: 2,
+                ..
+            })
+        ));
+        assert_eq!(
+            NoSpaceLeftOnDevice::solve_part_2("$ ls\n50000000 a"),
+            Ok(Answer::Integer(50000000))
+        );
+    }
+
+    #[test]
+    fn test_partial_paths_from_root_path() {
+        let paths = get_all_partial_paths_from_path_elements(Vec::new());
+        assert_eq!(paths, vec!["/"]);
+    }
+
+    #[test]
+    fn test_partial_paths_from_path() {
+        let paths = get_all_partial_paths_from_path_elements(vec!["a", "b", "c"]);
+        assert_eq!(paths, vec!["/", "/a", "/a/b", "/a/b/c"]);
+    }
+
+    #[test]
+    fn test_size_sum_of_directory_children() {
+        let sizes_sum = get_size_sum_of_directory_children(&[
+            DirChild::File(1, String::from("a")),
+            DirChild::File(2, String::from("b")),
+            DirChild::Dir(String::from("c")),
+            DirChild::File(3, String::from("d")),
+        ]);
+        assert_eq!(sizes_sum, 6);
+    }
+
+    #[test]
+    fn test_size_sum_of_directory_children_without_files() {
+        let sizes_sum = get_size_sum_of_directory_children(&[DirChild::Dir(String::from("a"))]);
+        assert_eq!(sizes_sum, 0);
+    }
+
+    #[test]
+    fn test_directory_sizes_aggregation() {
+        let shell_commands = vec![
+            ShellCommand::Cd(CdDirection::Root),
+            ShellCommand::Cd(CdDirection::Child(String::from("a"))),
+            ShellCommand::Ls(vec![
+                DirChild::Dir(String::from("abc")),
+                DirChild::File(123, String::from("test.txt")),
+            ]),
+            ShellCommand::Cd(CdDirection::Root),
+            ShellCommand::Ls(vec![DirChild::Dir(String::from("def"))]),
+        ];
+        let aggregated_directory_sizes =
+            aggregate_directory_sizes_from_shell_lines(&shell_commands);
+
+        let correct_sizes = [(String::from("/"), 123), (String::from("/a"), 123)]
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(aggregated_directory_sizes, correct_sizes);
+    }
+}
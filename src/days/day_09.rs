@@ -0,0 +1,311 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::geometry::{Direction, Point2};
+use crate::parsing::{normalize_input, parse_token, split_token};
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+const DAY: u8 = 9;
+
+pub fn parse_movement_line(line: &str) -> Result<(u32, Direction)> {
+    let (direction, amount) = split_token(DAY, line, line.trim(), " ")?;
+    let amount = parse_token(DAY, line, amount, "a movement amount")?;
+    match direction.parse::<Direction>() {
+        Ok(direction) if !direction.is_diagonal() => Ok((amount, direction)),
+        _ => Err(ParseError::new(
+            DAY,
+            line,
+            direction,
+            "one of the directions `R`, `L`, `U` or `D`",
+        )),
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(u32, Direction)>> {
+    let input = &normalize_input(input);
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_movement_line(line).map_err(|e| e.shift_lines(i)))
+        .collect()
+}
+
+pub fn get_next_rope_knot_position(
+    current_rope_knot_position: &Point2<i32>,
+    rope_predecessor_position: &Point2<i32>,
+) -> Point2<i32> {
+    if current_rope_knot_position.chebyshev_distance(*rope_predecessor_position) <= 1 {
+        *current_rope_knot_position
+    } else {
+        *current_rope_knot_position
+            + (*rope_predecessor_position - *current_rope_knot_position).signum()
+    }
+}
+
+// The rope moves on a plane where y grows upwards, unlike the rows of a map
+pub fn get_movement_offset(movement_direction: &Direction) -> Point2<i32> {
+    let offset = movement_direction.get_offset::<i32>();
+    Point2::new(offset.x, -offset.y)
+}
+
+pub fn execute_rope_movement_step(
+    movement_direction: &Direction,
+    rope_knot_positions: &[Point2<i32>],
+) -> Vec<Point2<i32>> {
+    let updated_rope_head_position =
+        rope_knot_positions[0] + get_movement_offset(movement_direction);
+    let mut updated_rope_knot_positions = vec![updated_rope_head_position];
+    for (i, rope_knot_position) in rope_knot_positions.iter().skip(1).enumerate() {
+        let updated_rope_knot_position =
+            get_next_rope_knot_position(rope_knot_position, &updated_rope_knot_positions[i]);
+        updated_rope_knot_positions.push(updated_rope_knot_position);
+    }
+
+    updated_rope_knot_positions
+}
+
+pub fn execute_rope_movement(
+    rope_length: usize,
+    moves: &[(u32, Direction)],
+) -> Vec<Vec<Point2<i32>>> {
+    let mut current_rope_knot_positions = vec![Point2::default(); rope_length];
+    let mut rope_knot_paths = vec![vec![Point2::default()]; rope_length];
+
+    for (amount, movement_direction) in moves {
+        for _ in 0..*amount {
+            current_rope_knot_positions =
+                execute_rope_movement_step(movement_direction, &current_rope_knot_positions);
+            for i in 0..rope_length {
+                rope_knot_paths[i].push(current_rope_knot_positions[i]);
+            }
+        }
+    }
+
+    rope_knot_paths
+}
+
+pub fn get_unique_path_positions(path: &[Point2<i32>]) -> HashSet<Point2<i32>> {
+    HashSet::from_iter(path.iter().cloned())
+}
+
+pub struct RopeBridge;
+
+impl Solution for RopeBridge {
+    type Input = Vec<(u32, Direction)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(movements: &Self::Input) -> SolveResult<Answer> {
+        let rope_knot_paths = execute_rope_movement(2, movements);
+        Ok(get_unique_path_positions(&rope_knot_paths[1]).len().into())
+    }
+
+    fn part2(movements: &Self::Input) -> SolveResult<Answer> {
+        let rope_knot_paths = execute_rope_movement(10, movements);
+        Ok(get_unique_path_positions(&rope_knot_paths[9]).len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_parsing() {
+        let movements = parse_input(
+            "L 1
+            R 2
+            U 3
+            D 4",
+        );
+        assert_eq!(
+            movements,
+            Ok(vec![
+                (1, Direction::Left),
+                (2, Direction::Right),
+                (3, Direction::Up),
+                (4, Direction::Down)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("R 1\nX 2").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.text, "X");
+
+        let error = parse_input("R -1").unwrap_err();
+        assert_eq!(error.column, 3);
+        assert_eq!(error.expected, "a movement amount");
+
+        let error = parse_input("R1").unwrap_err();
+        assert_eq!(error.expected, "a text containing ` `");
+    }
+
+    #[test]
+    fn test_next_tail_position() {
+        // Tail touches head -> No movement
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(0, 0)),
+            Point2::new(0, 0)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(1, 0)),
+            Point2::new(0, 0)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(-1, 0)),
+            Point2::new(0, 0)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(0, 1)),
+            Point2::new(0, 0)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(0, -1)),
+            Point2::new(0, 0)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(1, 1)),
+            Point2::new(0, 0)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(1, -1)),
+            Point2::new(0, 0)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(-1, 1)),
+            Point2::new(0, 0)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(-1, -1)),
+            Point2::new(0, 0)
+        );
+
+        // Tail movement on straight line
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(2, 0)),
+            Point2::new(1, 0)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(-2, 0)),
+            Point2::new(-1, 0)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(0, 2)),
+            Point2::new(0, 1)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(0, -2)),
+            Point2::new(0, -1)
+        );
+
+        // Tail movement on diagonal line
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(1, 2)),
+            Point2::new(1, 1)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(2, 1)),
+            Point2::new(1, 1)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(1, -2)),
+            Point2::new(1, -1)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(2, -1)),
+            Point2::new(1, -1)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(-1, 2)),
+            Point2::new(-1, 1)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(-2, 1)),
+            Point2::new(-1, 1)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(-1, -2)),
+            Point2::new(-1, -1)
+        );
+        assert_eq!(
+            get_next_rope_knot_position(&Point2::new(0, 0), &Point2::new(-2, -1)),
+            Point2::new(-1, -1)
+        );
+    }
+
+    #[test]
+    fn test_execute_rope_movement() {
+        let moves = vec![
+            (1, Direction::Left),
+            (2, Direction::Right),
+            (3, Direction::Up),
+            (4, Direction::Down),
+        ];
+        let rope_knot_paths = execute_rope_movement(2, &moves);
+        assert_eq!(
+            rope_knot_paths[0],
+            vec![
+                Point2::new(0, 0),
+                Point2::new(-1, 0),
+                Point2::new(0, 0),
+                Point2::new(1, 0),
+                Point2::new(1, 1),
+                Point2::new(1, 2),
+                Point2::new(1, 3),
+                Point2::new(1, 2),
+                Point2::new(1, 1),
+                Point2::new(1, 0),
+                Point2::new(1, -1),
+            ]
+        );
+        assert_eq!(
+            rope_knot_paths[1],
+            vec![
+                Point2::new(0, 0),
+                Point2::new(0, 0),
+                Point2::new(0, 0),
+                Point2::new(0, 0),
+                Point2::new(0, 0),
+                Point2::new(1, 1),
+                Point2::new(1, 2),
+                Point2::new(1, 2),
+                Point2::new(1, 2),
+                Point2::new(1, 1),
+                Point2::new(1, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unique_path_positions() {
+        let path = vec![
+            Point2::new(0, 0),
+            Point2::new(-1, 0),
+            Point2::new(0, 0),
+            Point2::new(1, 0),
+            Point2::new(1, 1),
+            Point2::new(1, 2),
+            Point2::new(1, 3),
+            Point2::new(1, 2),
+            Point2::new(1, 1),
+            Point2::new(1, 0),
+            Point2::new(1, -1),
+        ];
+        assert_eq!(
+            get_unique_path_positions(&path),
+            HashSet::from_iter(vec![
+                Point2::new(0, 0),
+                Point2::new(-1, 0),
+                Point2::new(1, 0),
+                Point2::new(1, 1),
+                Point2::new(1, 2),
+                Point2::new(1, 3),
+                Point2::new(1, -1),
+            ])
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crate::error::{Result, SolveError, SolveResult};
+use crate::parsing::{normalize_input, parse_line, parse_lines, LineParser};
+use crate::solution::{Answer, Solution};
+
+const DAY: u8 = 10;
+
+#[derive(Debug, PartialEq)]
+pub enum Instruction {
+    NoOp,
+    AddX(i32),
+}
+
+impl Instruction {
+    pub fn new(instruction_line: &str) -> Result<Self> {
+        parse_line(DAY, instruction_line, Self::parse)
+    }
+
+    pub fn parse(parser: &mut LineParser) -> Result<Self> {
+        if parser.try_literal("addx") {
+            Ok(Self::AddX(parser.signed("an integer value")?))
+        } else if parser.try_literal("noop") {
+            Ok(Self::NoOp)
+        } else {
+            Err(parser.unexpected("`addx` or `noop`"))
+        }
+    }
+
+    pub fn execute_instruction(&self, register_x: i32) -> Vec<(i32, i32)> {
+        match self {
+            Instruction::NoOp => vec![(register_x, register_x)],
+            Instruction::AddX(n) => vec![(register_x, register_x), (register_x, register_x + n)],
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>> {
+    let input = &normalize_input(input);
+    parse_lines(DAY, input, Instruction::parse)
+}
+
+pub fn execute_program(register_x: i32, program_instructions: &[Instruction]) -> Vec<(i32, i32)> {
+    let mut register_values_during_program = vec![(register_x, register_x)];
+    for instruction in program_instructions {
+        register_values_during_program.extend(
+            instruction.execute_instruction(register_values_during_program.last().unwrap().1),
+        );
+    }
+
+    register_values_during_program
+}
+
+pub fn get_signal_strengths_during_cycles(
+    cycles: Vec<usize>,
+    register_values: &[(i32, i32)],
+) -> Option<Vec<i32>> {
+    cycles
+        .iter()
+        .cloned()
+        .map(|cycle| Some(register_values.get(cycle)?.0 * cycle as i32))
+        .collect()
+}
+
+pub fn draw_crt_line(register_values: &[(i32, i32)]) -> String {
+    register_values
+        .iter()
+        .map(|(value_during_cycle, _)| (value_during_cycle - 1)..=(value_during_cycle + 1))
+        .enumerate()
+        .map(|(crt_position, sprite_pixel_positions)| {
+            if sprite_pixel_positions.contains(&(crt_position as i32)) {
+                '#'
+            } else {
+                '.'
+            }
+        })
+        .collect()
+}
+
+pub struct CathodeRayTube;
+
+impl Solution for CathodeRayTube {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(program_instructions: &Self::Input) -> SolveResult<Answer> {
+        let register_values_during_program = execute_program(1, program_instructions);
+        match get_signal_strengths_during_cycles(
+            vec![20, 60, 100, 140, 180, 220],
+            &register_values_during_program,
+        ) {
+            Some(signal_strengths) => Ok(signal_strengths.iter().sum::<i32>().into()),
+            None => Err(SolveError::no_answer(
+                DAY,
+                1,
+                "the program ends before the 220th cycle",
+            )),
+        }
+    }
+
+    fn part2(program_instructions: &Self::Input) -> SolveResult<Answer> {
+        let register_values_during_program = execute_program(1, program_instructions);
+        Ok(register_values_during_program[1..]
+            .chunks(40)
+            .map(draw_crt_line)
+            .collect::<Vec<String>>()
+            .join("\n")
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instruction_creation() {
+        assert_eq!(Instruction::new("noop"), Ok(Instruction::NoOp));
+        assert_eq!(Instruction::new("addx 123"), Ok(Instruction::AddX(123)));
+        assert_eq!(Instruction::new("addx 0"), Ok(Instruction::AddX(0)));
+        assert_eq!(Instruction::new("addx -456"), Ok(Instruction::AddX(-456)));
+    }
+
+    #[test]
+    fn test_instruction_creation_errors() {
+        let error = Instruction::new("addx").unwrap_err();
+        assert_eq!(error.column, 5);
+        assert_eq!(error.expected, "an integer value");
+
+        let error = Instruction::new("  addy 1").unwrap_err();
+        assert_eq!(error.column, 3);
+        assert_eq!(error.text, "addy");
+
+        let error = Instruction::new("noop 1").unwrap_err();
+        assert_eq!(error.column, 6);
+        assert_eq!(error.expected, "the end of the line");
+    }
+
+    #[test]
+    fn test_input_parsing() {
+        let input = "addx 1
+        noop
+        addx -1
+        noop";
+        let instructions = parse_input(input);
+        assert_eq!(
+            instructions,
+            Ok(vec![
+                Instruction::AddX(1),
+                Instruction::NoOp,
+                Instruction::AddX(-1),
+                Instruction::NoOp,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_program_execution() {
+        let program_instructions = vec![
+            Instruction::AddX(1),
+            Instruction::NoOp,
+            Instruction::AddX(-1),
+            Instruction::NoOp,
+        ];
+        let register_values_during_program = execute_program(0, &program_instructions);
+        assert_eq!(
+            register_values_during_program,
+            vec![(0, 0), (0, 0), (0, 1), (1, 1), (1, 1), (1, 0), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_signal_strengths_during_cycles() {
+        let register_values_during_program = vec![(0, 0), (0, 0), (0, 1), (1, 1), (1, 2), (2, 2)];
+        let signal_strengths_during_cycles =
+            get_signal_strengths_during_cycles(vec![1, 3, 5], &register_values_during_program);
+        assert_eq!(signal_strengths_during_cycles, Some(vec![0, 3, 10]));
+        assert_eq!(
+            get_signal_strengths_during_cycles(vec![20], &register_values_during_program),
+            None
+        );
+    }
+
+    #[test]
+    fn test_solution_with_short_program() {
+        assert!(matches!(
+            CathodeRayTube::solve_part_1("noop"),
+            Err(SolveError::NoAnswer {
+                day: 10,
+                part: 1,
+                ..
+            })
+        ));
+        assert_eq!(
+            CathodeRayTube::solve_part_2("noop\naddx 1"),
+            Ok(Answer::from("###"))
+        );
+    }
+
+    #[test]
+    fn test_draw_crt_line() {
+        let register_values = vec![(1, 1), (1, 16), (16, 16), (16, -11)];
+        assert_eq!(draw_crt_line(&register_values), String::from("##.."))
+    }
+}
@@ -0,0 +1,581 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::parsing::{
+    end_of_line, normalize_input, parse_blocks, split_blocks, BlockParser, LineParser,
+};
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+const DAY: u8 = 11;
+
+pub enum WorryLevelReduction {
+    Divide(u64),
+    Modulo(u64),
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    pub item_worry_levels: Vec<u64>,
+    pub inspections_counter: u64,
+    pub worry_level_change_operation: Rc<dyn Fn(u64) -> u64>,
+    pub worry_level_test_parameter: u64,
+    pub worry_level_test_successful_monkey_index: usize,
+    pub worry_level_test_unsuccessful_monkey_index: usize,
+}
+
+impl Monkey {
+    pub fn new(input: &str, monkey_index: usize, monkeys_number: usize) -> Result<Self> {
+        Self::parse(
+            &mut BlockParser::new(DAY, input, 0),
+            monkey_index,
+            monkeys_number,
+        )
+    }
+
+    // The monkey's index is its position within the input, its throw targets have to be other
+    // monkeys of the input
+    pub fn parse(
+        block: &mut BlockParser,
+        monkey_index: usize,
+        monkeys_number: usize,
+    ) -> Result<Self> {
+        block.parse_next_line("a monkey", |parser| {
+            parser.literal("Monkey")?;
+            let (index, index_text) =
+                parser.consumed(|parser| parser.unsigned::<usize>("a monkey index"))?;
+            if index != monkey_index {
+                let expected = format!("the monkey index {}", monkey_index);
+                return Err(parser.error(index_text, &expected));
+            }
+            parser.literal(":")
+        })?;
+        let item_worry_levels =
+            block.parse_next_line("the starting items", parse_item_worry_levels)?;
+        let worry_level_change_operation =
+            block.parse_next_line("the operation", parse_worry_level_change_operation)?;
+        let worry_level_test_parameter =
+            block.parse_next_line("the test", parse_worry_level_test_parameter)?;
+        let worry_level_test_successful_monkey_index = block
+            .parse_next_line("the throw target if the test is true", |parser| {
+                parse_monkey_throw_index(parser, "true", monkey_index, monkeys_number)
+            })?;
+        let worry_level_test_unsuccessful_monkey_index = block
+            .parse_next_line("the throw target if the test is false", |parser| {
+                parse_monkey_throw_index(parser, "false", monkey_index, monkeys_number)
+            })?;
+        Ok(Self {
+            inspections_counter: 0,
+            item_worry_levels,
+            worry_level_change_operation,
+            worry_level_test_parameter,
+            worry_level_test_successful_monkey_index,
+            worry_level_test_unsuccessful_monkey_index,
+        })
+    }
+
+    pub fn execute_turn(
+        &mut self,
+        new_item_worry_levels: &[u64],
+        worry_level_reduction: &WorryLevelReduction,
+    ) -> HashMap<usize, Vec<u64>> {
+        let item_worry_levels = self
+            .item_worry_levels
+            .iter()
+            .cloned()
+            .chain(new_item_worry_levels.iter().cloned())
+            .collect::<Vec<u64>>();
+
+        self.item_worry_levels.clear();
+
+        let mut item_throws: HashMap<usize, Vec<u64>> = HashMap::new();
+
+        for item_worry_level in item_worry_levels {
+            self.inspections_counter += 1;
+
+            let changed_item_worry_level: u64 =
+                (self.worry_level_change_operation)(item_worry_level);
+
+            let changed_item_worry_level = match worry_level_reduction {
+                WorryLevelReduction::Divide(n) => changed_item_worry_level / n,
+                WorryLevelReduction::Modulo(n) => changed_item_worry_level % n,
+            };
+
+            let monkey_throw_index =
+                if changed_item_worry_level % self.worry_level_test_parameter == 0 {
+                    self.worry_level_test_successful_monkey_index
+                } else {
+                    self.worry_level_test_unsuccessful_monkey_index
+                };
+
+            insert_item_throw(
+                monkey_throw_index,
+                vec![changed_item_worry_level],
+                &mut item_throws,
+            );
+        }
+
+        item_throws
+    }
+}
+
+pub fn parse_item_worry_levels(parser: &mut LineParser) -> Result<Vec<u64>> {
+    parser.literal("Starting items:")?;
+    // A monkey can start without any items
+    if parser.is_at_end() {
+        return Ok(Vec::new());
+    }
+    parser.separated(",", |parser| parser.unsigned("an item worry level"))
+}
+
+fn parse_operand(parser: &mut LineParser) -> Result<Option<u64>> {
+    if parser.try_literal("old") {
+        Ok(None)
+    } else {
+        Ok(Some(parser.unsigned("`old` or an integer")?))
+    }
+}
+
+pub fn parse_worry_level_change_operation(
+    parser: &mut LineParser,
+) -> Result<Rc<dyn Fn(u64) -> u64>> {
+    parser.literal("Operation:")?;
+    parser.literal("new")?;
+    parser.literal("=")?;
+
+    let mut constants = Vec::new();
+    let operand_1 = parse_operand(parser)?;
+    let operator = parser.one_of(&[("+", '+'), ("*", '*')], "`+` or `*`")?;
+    let operand_2 = parse_operand(parser)?;
+    constants.extend(operand_1);
+    constants.extend(operand_2);
+
+    let input_parameter_usage_counter = 2 - constants.len();
+
+    match operator {
+        '+' => Ok(Rc::new(move |parameter| {
+            let mut calculation_inputs = vec![parameter; input_parameter_usage_counter];
+            calculation_inputs.extend(constants.iter());
+            calculation_inputs.iter().sum()
+        })),
+        _ => Ok(Rc::new(move |parameter| {
+            let mut calculation_inputs = vec![parameter; input_parameter_usage_counter];
+            calculation_inputs.extend(constants.iter());
+            calculation_inputs.iter().product()
+        })),
+    }
+}
+
+pub fn parse_worry_level_test_parameter(parser: &mut LineParser) -> Result<u64> {
+    parser.literal("Test:")?;
+    parser.literal("divisible")?;
+    parser.literal("by")?;
+    let (divisor, divisor_text) = parser.consumed(|parser| parser.unsigned("a divisor"))?;
+    if divisor == 0 {
+        return Err(parser.error(divisor_text, "a divisor greater than 0"));
+    }
+    Ok(divisor)
+}
+
+// The throw targets have the format `If <condition>: throw to monkey <index>`, the target for a
+// true test comes first
+pub fn parse_monkey_throw_index(
+    parser: &mut LineParser,
+    condition: &str,
+    monkey_index: usize,
+    monkeys_number: usize,
+) -> Result<usize> {
+    parser.literal("If")?;
+    parser.literal(condition)?;
+    parser.literal(":")?;
+    parser.literal("throw")?;
+    parser.literal("to")?;
+    parser.literal("monkey")?;
+    let (target, target_text) = parser.consumed(|parser| parser.unsigned("a monkey index"))?;
+    if target >= monkeys_number || target == monkey_index {
+        let expected = format!(
+            "a monkey index below {} other than {}",
+            monkeys_number, monkey_index
+        );
+        return Err(parser.error(target_text, &expected));
+    }
+    Ok(target)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Monkey>> {
+    let input = &normalize_input(input);
+    let monkeys_number = split_blocks(input).len();
+    // Monkey business is the product of the two most active monkeys
+    if monkeys_number < 2 {
+        let last_line = input.lines().last().unwrap_or(input);
+        return Err(ParseError::new(
+            DAY,
+            last_line,
+            end_of_line(last_line),
+            "an empty line followed by a second monkey",
+        )
+        .shift_lines(input.lines().count().saturating_sub(1)));
+    }
+    let mut monkey_index = 0;
+    parse_blocks(DAY, input, |block| {
+        let monkey = Monkey::parse(block, monkey_index, monkeys_number)?;
+        monkey_index += 1;
+        Ok(monkey)
+    })
+}
+
+pub fn insert_item_throw(
+    index: usize,
+    thrown_items: Vec<u64>,
+    item_throws: &mut HashMap<usize, Vec<u64>>,
+) {
+    if let Some(monkey_item_throws) = item_throws.get_mut(&index) {
+        monkey_item_throws.extend(thrown_items);
+    } else {
+        item_throws.insert(index, thrown_items);
+    }
+}
+
+pub fn execute_monkey_throwing_round(
+    monkeys: &mut [Monkey],
+    worry_level_reduction: WorryLevelReduction,
+) {
+    let mut item_throws: HashMap<usize, Vec<u64>> = HashMap::new();
+
+    for (index, monkey) in monkeys.iter_mut().enumerate() {
+        let items_thrown_to_monkey = item_throws.remove(&index).unwrap_or_default();
+        let items_thrown_from_monkey =
+            monkey.execute_turn(&items_thrown_to_monkey, &worry_level_reduction);
+        for (monkey_index, thrown_items) in items_thrown_from_monkey {
+            insert_item_throw(monkey_index, thrown_items, &mut item_throws);
+        }
+    }
+
+    for (monkey_index, thrown_items) in item_throws {
+        monkeys[monkey_index].item_worry_levels.extend(thrown_items);
+    }
+}
+
+fn get_monkey_business_level(monkeys: &[Monkey]) -> u64 {
+    let mut inspections = monkeys
+        .iter()
+        .map(|m| m.inspections_counter)
+        .collect::<Vec<u64>>();
+    inspections.sort();
+    inspections.reverse();
+    inspections[0] * inspections[1]
+}
+
+pub struct MonkeyInTheMiddle;
+
+impl Solution for MonkeyInTheMiddle {
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(monkeys: &Self::Input) -> SolveResult<Answer> {
+        let mut monkeys = monkeys.clone();
+        for _ in 0..20 {
+            execute_monkey_throwing_round(&mut monkeys, WorryLevelReduction::Divide(3));
+        }
+        Ok(get_monkey_business_level(&monkeys).into())
+    }
+
+    fn part2(monkeys: &Self::Input) -> SolveResult<Answer> {
+        let mut monkeys = monkeys.clone();
+        let worry_level_reduction_factor = monkeys
+            .iter()
+            .map(|m| m.worry_level_test_parameter)
+            .product();
+        for _ in 0..10000 {
+            execute_monkey_throwing_round(
+                &mut monkeys,
+                WorryLevelReduction::Modulo(worry_level_reduction_factor),
+            );
+        }
+        Ok(get_monkey_business_level(&monkeys).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::parse_line;
+
+    const FIRST_MONKEY: &str = "Monkey 0:
+  Starting items: 1
+  Operation: new = old * 2
+  Test: divisible by 8
+    If true: throw to monkey 1
+    If false: throw to monkey 1";
+
+    const SECOND_MONKEY: &str = "Monkey 1:
+  Starting items: 4
+  Operation: new = old + 3
+  Test: divisible by 5
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+
+    #[test]
+    fn test_parse_item_worry_levels() {
+        assert_eq!(
+            parse_line(DAY, "Starting items: 123", parse_item_worry_levels),
+            Ok(vec![123])
+        );
+        assert_eq!(
+            parse_line(DAY, "Starting items: 1, 22, 333", parse_item_worry_levels),
+            Ok(vec![1, 22, 333])
+        );
+        assert_eq!(
+            parse_line(DAY, "Starting items:", parse_item_worry_levels),
+            Ok(Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_parse_worry_level_change_operation() {
+        let tests = vec![
+            ("Operation: new = old + 2", (5u64, 7)),
+            ("Operation: new = 2 + old", (5u64, 7)),
+            ("Operation: new = old + old", (5u64, 10)),
+            ("Operation: new = 2 + 3", (5u64, 5)),
+            ("Operation: new = old * 2", (5u64, 10)),
+            ("Operation: new = 2 * old", (5u64, 10)),
+            ("Operation: new = old * old", (5u64, 25)),
+            ("Operation: new = 2 * 3", (5u64, 6)),
+        ];
+
+        for (operation, (parameter, correct_result)) in tests {
+            let operation_function =
+                parse_line(DAY, operation, parse_worry_level_change_operation).unwrap();
+            assert_eq!(operation_function(parameter), correct_result);
+        }
+    }
+
+    #[test]
+    fn test_parse_worry_level_test_parameter() {
+        assert_eq!(
+            parse_line(
+                DAY,
+                "Test: divisible by 123",
+                parse_worry_level_test_parameter
+            ),
+            Ok(123)
+        );
+    }
+
+    #[test]
+    fn test_parse_monkey_throw_index() {
+        assert_eq!(
+            parse_line(DAY, "If true: throw to monkey 123", |parser| {
+                parse_monkey_throw_index(parser, "true", 0, 124)
+            }),
+            Ok(123)
+        );
+        assert_eq!(
+            parse_line(DAY, "If false: throw to monkey 456", |parser| {
+                parse_monkey_throw_index(parser, "false", 0, 457)
+            }),
+            Ok(456)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_line(DAY, "Starting items: 1, x", parse_item_worry_levels).unwrap_err();
+        assert_eq!(error.column, 20);
+        assert_eq!(error.text, "x");
+
+        let error = parse_line(
+            DAY,
+            "Operation: new = old - 1",
+            parse_worry_level_change_operation,
+        )
+        .err();
+        assert_eq!(error.unwrap().text, "-");
+
+        let error = parse_line(
+            DAY,
+            "Operation: new = old *",
+            parse_worry_level_change_operation,
+        )
+        .err();
+        assert_eq!(error.unwrap().expected, "`old` or an integer");
+
+        let error = parse_line(DAY, "If false: throw to 4", |parser| {
+            parse_monkey_throw_index(parser, "false", 0, 5)
+        })
+        .unwrap_err();
+        assert_eq!(error.text, "4");
+        assert_eq!(error.expected, "`monkey`");
+
+        let error = parse_line(DAY, "If false: throw to monkey 4", |parser| {
+            parse_monkey_throw_index(parser, "true", 0, 5)
+        })
+        .unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (4, "false:"));
+        assert_eq!(error.expected, "`true`");
+
+        for (monkey_index, monkeys_number) in [(0, 4), (4, 5)] {
+            let error = parse_line(DAY, "If true: throw to monkey 4", |parser| {
+                parse_monkey_throw_index(parser, "true", monkey_index, monkeys_number)
+            })
+            .unwrap_err();
+            assert_eq!((error.column, error.text.as_str()), (26, "4"));
+        }
+
+        let error = parse_line(
+            DAY,
+            "Test: divisible by 0",
+            parse_worry_level_test_parameter,
+        )
+        .unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (20, "0"));
+        assert_eq!(error.expected, "a divisor greater than 0");
+    }
+
+    #[test]
+    fn test_monkey_creation() {
+        let input = "Monkey 1:
+          Starting items: 1, 2, 3
+          Operation: new = old * 2
+          Test: divisible by 8
+            If true: throw to monkey 2
+            If false: throw to monkey 3";
+        let monkey = Monkey::new(input, 1, 4).unwrap();
+        assert_eq!(monkey.item_worry_levels, vec![1, 2, 3]);
+        assert_eq!(monkey.inspections_counter, 0);
+        assert_eq!((monkey.worry_level_change_operation)(3), 6);
+        assert_eq!(monkey.worry_level_test_parameter, 8);
+        assert_eq!(monkey.worry_level_test_successful_monkey_index, 2);
+        assert_eq!(monkey.worry_level_test_unsuccessful_monkey_index, 3);
+    }
+
+    #[test]
+    fn test_input_parsing() {
+        let input = "Monkey 0:
+          Starting items: 1, 2, 3
+          Operation: new = old * 2
+          Test: divisible by 8
+            If true: throw to monkey 1
+            If false: throw to monkey 1
+
+        Monkey 1:
+          Starting items: 4, 5, 6
+          Operation: new = old * 2
+          Test: divisible by 8
+            If true: throw to monkey 0
+            If false: throw to monkey 0";
+
+        let monkeys = parse_input(input).unwrap();
+        assert_eq!(monkeys.len(), 2);
+        assert_eq!(monkeys[1].worry_level_test_successful_monkey_index, 0);
+        assert_eq!(monkeys[0].item_worry_levels, vec![1, 2, 3]);
+        assert_eq!(monkeys[1].item_worry_levels, vec![4, 5, 6]);
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let input = "Monkey 0:
+          Starting items: 1
+          Operation: new = old * 2
+          Test: divisible by 8
+            If true: throw to monkey 1
+            If false: throw to monkey 1
+
+        Monkey 1:
+          Starting items: 4
+          Operation: new = old ^ 2
+          Test: divisible by 8
+            If true: throw to monkey 0
+            If false: throw to monkey 0";
+        let error = parse_input(input).err().unwrap();
+        assert_eq!(error.line, 10);
+        assert_eq!(error.text, "^");
+
+        let swapped_targets = format!(
+            "Monkey 0:
+          Starting items: 1
+          Operation: new = old * 2
+          Test: divisible by 8
+            If false: throw to monkey 1
+            If true: throw to monkey 1\n\n{}",
+            SECOND_MONKEY
+        );
+        let error = parse_input(&swapped_targets).err().unwrap();
+        assert_eq!((error.line, error.text.as_str()), (5, "false:"));
+        assert_eq!(error.expected, "`true`");
+
+        let error = parse_input(&format!(
+            "Monkey 0:\n  Starting items: 1\n\n{}",
+            SECOND_MONKEY
+        ))
+        .err()
+        .unwrap();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.expected, "the operation");
+
+        let error = parse_input(&format!(
+            "{}\n  Unexpected\n\n{}",
+            FIRST_MONKEY, SECOND_MONKEY
+        ))
+        .err()
+        .unwrap();
+        assert_eq!((error.line, error.column), (7, 3));
+        assert_eq!(error.expected, "the end of the block");
+
+        let error = parse_input(&format!("{}\n\n{}", SECOND_MONKEY, FIRST_MONKEY))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 8));
+        assert_eq!(error.expected, "the monkey index 0");
+
+        let error = parse_input(FIRST_MONKEY).err().unwrap();
+        assert_eq!((error.line, error.text.as_str()), (6, ""));
+        assert_eq!(error.expected, "an empty line followed by a second monkey");
+    }
+
+    #[test]
+    fn test_monkey_turn_execution() {
+        let mut monkey = Monkey {
+            item_worry_levels: vec![1],
+            inspections_counter: 0,
+            worry_level_change_operation: Rc::new(|worry_level| worry_level + 10),
+            worry_level_test_parameter: 3,
+            worry_level_test_successful_monkey_index: 1,
+            worry_level_test_unsuccessful_monkey_index: 2,
+        };
+        let item_throws = monkey.execute_turn(&[2], &WorryLevelReduction::Divide(3));
+        assert_eq!(monkey.inspections_counter, 2);
+        assert!(monkey.item_worry_levels.is_empty());
+        assert_eq!(item_throws, HashMap::from([(1, vec![3]), (2, vec![4])]));
+    }
+
+    #[test]
+    fn test_monkey_round_execution() {
+        let mut monkeys = vec![
+            Monkey {
+                item_worry_levels: vec![1],
+                inspections_counter: 0,
+                worry_level_change_operation: Rc::new(|worry_level| worry_level * 3),
+                worry_level_test_parameter: 1,
+                worry_level_test_successful_monkey_index: 1,
+                worry_level_test_unsuccessful_monkey_index: 1,
+            },
+            Monkey {
+                item_worry_levels: vec![2],
+                inspections_counter: 0,
+                worry_level_change_operation: Rc::new(|worry_level| worry_level * 3),
+                worry_level_test_parameter: 1,
+                worry_level_test_successful_monkey_index: 0,
+                worry_level_test_unsuccessful_monkey_index: 0,
+            },
+        ];
+        execute_monkey_throwing_round(&mut monkeys, WorryLevelReduction::Divide(3));
+        assert_eq!(monkeys[0].inspections_counter, 1);
+        assert_eq!(monkeys[0].item_worry_levels, vec![2, 1]);
+        assert_eq!(monkeys[1].inspections_counter, 2);
+        assert!(monkeys[1].item_worry_levels.is_empty());
+    }
+}
@@ -0,0 +1,292 @@
+use crate::error::{ParseError, Result, SolveError, SolveResult};
+use crate::grid::{parse_character_map, Grid, Point};
+use crate::parsing::{end_of_line, normalize_input};
+use crate::search::{breadth_first_search, SearchResult};
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+const DAY: u8 = 12;
+
+pub struct HillClimbingGraph {
+    pub start_nodes: HashSet<Point>,
+    pub goal_node: Point,
+    pub adjacency_list: HashMap<Point, HashSet<Point>>,
+}
+
+impl HillClimbingGraph {
+    pub fn new(hill_map: &Grid<char>, use_single_start_node: bool) -> Self {
+        let mut start_nodes = HashSet::new();
+        let mut goal_node = None;
+        let mut adjacency_list = HashMap::new();
+
+        for (point, hill) in hill_map.iter() {
+            if *hill == 'S' || (!use_single_start_node && *hill == 'a') {
+                start_nodes.insert(point);
+            } else if *hill == 'E' {
+                goal_node = Some(point);
+            }
+            let height = get_hill_height(*hill);
+            let mut hill_adjacency = HashSet::new();
+            for (neighbour, neighbour_height) in get_neighbour_heights(point, hill_map) {
+                if neighbour_height <= (height + 1) {
+                    hill_adjacency.insert(neighbour);
+                }
+            }
+
+            adjacency_list.insert(point, hill_adjacency);
+        }
+
+        Self {
+            start_nodes,
+            goal_node: goal_node.unwrap(),
+            adjacency_list,
+        }
+    }
+}
+
+pub fn get_hill_height(c: char) -> u32 {
+    match c {
+        'S' => 1,
+        'E' => 26,
+        _ => u32::from(c) - 96,
+    }
+}
+
+pub fn get_neighbour_heights(point: Point, hill_map: &Grid<char>) -> Vec<(Point, u32)> {
+    hill_map
+        .get_neighbours(point)
+        .map(|neighbour| (neighbour, get_hill_height(hill_map[neighbour])))
+        .collect()
+}
+
+pub fn parse_hill_map(input: &str) -> Result<Grid<char>> {
+    let hill_map = parse_character_map(
+        DAY,
+        input,
+        "a hill height from `a` to `z`, `S` or `E`",
+        "hills",
+        |hill| matches!(hill, 'a'..='z' | 'S' | 'E').then_some(hill),
+    )?;
+
+    for (marker, description) in [('S', "a start hill `S`"), ('E', "a goal hill `E`")] {
+        if !hill_map.iter().any(|(_, hill)| *hill == marker) {
+            let input = &normalize_input(input);
+            let last_line = input.lines().last().unwrap_or(input);
+            return Err(
+                ParseError::new(DAY, last_line, end_of_line(last_line), description)
+                    .shift_lines(hill_map.height().saturating_sub(1)),
+            );
+        }
+    }
+
+    Ok(hill_map)
+}
+
+pub fn parse_input(input: &str, use_single_start_node: bool) -> Result<HillClimbingGraph> {
+    Ok(HillClimbingGraph::new(
+        &parse_hill_map(input)?,
+        use_single_start_node,
+    ))
+}
+
+pub fn shortest_path_search(graph: &HillClimbingGraph) -> Option<SearchResult<Point, usize>> {
+    breadth_first_search(
+        graph.start_nodes.iter().copied(),
+        |node| *node == graph.goal_node,
+        |node| {
+            graph
+                .adjacency_list
+                .get(node)
+                .into_iter()
+                .flatten()
+                .copied()
+        },
+    )
+}
+
+pub fn shortest_path_length_search(graph: &HillClimbingGraph) -> Option<usize> {
+    shortest_path_search(graph).map(|result| result.cost)
+}
+
+fn get_shortest_path_answer(hill_map: &Grid<char>, part: usize) -> SolveResult<Answer> {
+    let hill_graph = HillClimbingGraph::new(hill_map, part == 1);
+    match shortest_path_length_search(&hill_graph) {
+        Some(length) => Ok(length.into()),
+        None => Err(SolveError::no_answer(
+            DAY,
+            part,
+            "the goal hill `E` can't be reached",
+        )),
+    }
+}
+
+pub struct HillClimbingAlgorithm;
+
+impl Solution for HillClimbingAlgorithm {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_hill_map(input)
+    }
+
+    fn part1(hill_map: &Self::Input) -> SolveResult<Answer> {
+        get_shortest_path_answer(hill_map, 1)
+    }
+
+    fn part2(hill_map: &Self::Input) -> SolveResult<Answer> {
+        get_shortest_path_answer(hill_map, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_hill_height() {
+        assert_eq!(get_hill_height('S'), 1);
+        assert_eq!(get_hill_height('E'), 26);
+        assert_eq!(get_hill_height('a'), 1);
+        assert_eq!(get_hill_height('z'), 26);
+    }
+
+    #[test]
+    fn test_get_neighbor_heights() {
+        let heights = Grid::from_rows(vec![
+            vec!['a', 'b', 'c'],
+            vec!['d', 'e', 'f'],
+            vec!['g', 'h', 'i'],
+        ]);
+        assert_eq!(
+            get_neighbour_heights(Point::new(0, 0), &heights),
+            vec![(Point::new(1, 0), 2), (Point::new(0, 1), 4)]
+        );
+        assert_eq!(
+            get_neighbour_heights(Point::new(2, 0), &heights),
+            vec![(Point::new(1, 0), 2), (Point::new(2, 1), 6)]
+        );
+        assert_eq!(
+            get_neighbour_heights(Point::new(0, 2), &heights),
+            vec![(Point::new(1, 2), 8), (Point::new(0, 1), 4)]
+        );
+        assert_eq!(
+            get_neighbour_heights(Point::new(2, 2), &heights),
+            vec![(Point::new(1, 2), 8), (Point::new(2, 1), 6)]
+        );
+        assert_eq!(
+            get_neighbour_heights(Point::new(1, 1), &heights),
+            vec![
+                (Point::new(0, 1), 4),
+                (Point::new(2, 1), 6),
+                (Point::new(1, 0), 2),
+                (Point::new(1, 2), 8)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_parsing() {
+        let input = "Sbc\nfed\nghE";
+        let graph = parse_input(input, true).unwrap();
+
+        assert_eq!(graph.start_nodes, HashSet::from([Point::new(0, 0)]));
+        assert_eq!(graph.goal_node, Point::new(2, 2));
+        assert_eq!(graph.adjacency_list.len(), 9);
+        assert_eq!(
+            graph.adjacency_list.get(&Point::new(0, 0)).unwrap().clone(),
+            HashSet::from([Point::new(1, 0)])
+        );
+        assert_eq!(
+            graph.adjacency_list.get(&Point::new(1, 0)).unwrap().clone(),
+            HashSet::from([Point::new(0, 0), Point::new(2, 0)])
+        );
+        assert_eq!(
+            graph.adjacency_list.get(&Point::new(2, 0)).unwrap().clone(),
+            HashSet::from([Point::new(1, 0), Point::new(2, 1)])
+        );
+        assert_eq!(
+            graph.adjacency_list.get(&Point::new(2, 1)).unwrap().clone(),
+            HashSet::from([Point::new(2, 0), Point::new(1, 1)])
+        );
+        assert_eq!(
+            graph.adjacency_list.get(&Point::new(1, 1)).unwrap().clone(),
+            HashSet::from([Point::new(2, 1), Point::new(1, 0), Point::new(0, 1)])
+        );
+        assert_eq!(
+            graph.adjacency_list.get(&Point::new(0, 1)).unwrap().clone(),
+            HashSet::from([Point::new(1, 1), Point::new(0, 0), Point::new(0, 2)])
+        );
+        assert_eq!(
+            graph.adjacency_list.get(&Point::new(0, 2)).unwrap().clone(),
+            HashSet::from([Point::new(0, 1), Point::new(1, 2)])
+        );
+        assert_eq!(
+            graph.adjacency_list.get(&Point::new(1, 2)).unwrap().clone(),
+            HashSet::from([Point::new(0, 2), Point::new(1, 1)])
+        );
+        assert_eq!(
+            graph.adjacency_list.get(&Point::new(2, 2)).unwrap().clone(),
+            HashSet::from([Point::new(1, 2), Point::new(2, 1)])
+        );
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("Sbc\nf1d\nghE", true).err().unwrap();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 2);
+
+        let error = parse_input("Sbc\nfe\nghE", true).err().unwrap();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.expected, "a line of 3 hills");
+
+        let error = parse_input("Sbc\nfed\nghi", true).err().unwrap();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 4);
+        assert_eq!(error.expected, "a goal hill `E`");
+    }
+
+    #[test]
+    fn test_path_search() {
+        let graph = HillClimbingGraph {
+            start_nodes: HashSet::from([Point::new(0, 0)]),
+            goal_node: Point::new(1, 1),
+            adjacency_list: HashMap::from([
+                (
+                    Point::new(0, 0),
+                    HashSet::from([Point::new(1, 0), Point::new(0, 1)]),
+                ),
+                (Point::new(1, 0), HashSet::new()),
+                (Point::new(0, 1), HashSet::from([Point::new(1, 1)])),
+            ]),
+        };
+        let path = shortest_path_length_search(&graph).unwrap();
+        assert_eq!(path, 2);
+        assert_eq!(
+            shortest_path_search(&graph).unwrap().path,
+            vec![Point::new(0, 0), Point::new(0, 1), Point::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_solution_with_unreachable_goal() {
+        for input in ["SE", "Sz\nbE"] {
+            assert!(matches!(
+                HillClimbingAlgorithm::solve_part_1(input),
+                Err(SolveError::NoAnswer {
+                    day: 12,
+                    part: 1,
+                    ..
+                })
+            ));
+            assert!(matches!(
+                HillClimbingAlgorithm::solve_part_2(input),
+                Err(SolveError::NoAnswer {
+                    day: 12,
+                    part: 2,
+                    ..
+                })
+            ));
+        }
+    }
+}
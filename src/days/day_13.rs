@@ -0,0 +1,444 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::parsing::{end_of_line, normalize_input, parse_token, split_blocks};
+use crate::solution::{Answer, Solution};
+use std::cmp::Ordering;
+
+const DAY: u8 = 13;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PacketData {
+    Integer(u32),
+    NestedList(Vec<PacketData>),
+}
+
+pub fn parse_packet(line: &str) -> Result<Vec<PacketData>> {
+    parse_packet_in_line(line, line.trim())
+}
+
+fn parse_packet_in_line(line: &str, packet: &str) -> Result<Vec<PacketData>> {
+    if !packet.starts_with('[') || !packet.ends_with(']') || packet.len() < 2 {
+        return Err(ParseError::new(DAY, line, packet, "a list in brackets"));
+    }
+
+    let elements_string = &packet[1..packet.len() - 1];
+    if elements_string.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut packet_elements = Vec::new();
+    let mut current_element_start_index = 0;
+    let mut nesting_depth = 0;
+    for (i, c) in elements_string.char_indices() {
+        if c == ',' && nesting_depth == 0 {
+            packet_elements.push(&elements_string[current_element_start_index..i]);
+            current_element_start_index = i + 1
+        } else if c == '[' {
+            nesting_depth += 1;
+        } else if c == ']' {
+            if nesting_depth == 0 {
+                let bracket = &elements_string[i..i + 1];
+                return Err(ParseError::new(DAY, line, bracket, "a `,` or an element"));
+            }
+            nesting_depth -= 1;
+        }
+    }
+    if nesting_depth != 0 {
+        let bracket = &packet[packet.len() - 1..];
+        return Err(ParseError::new(DAY, line, bracket, "a closing `]`"));
+    }
+    packet_elements.push(&elements_string[current_element_start_index..]);
+
+    packet_elements
+        .iter()
+        .map(|d| {
+            if d.starts_with('[') {
+                Ok(PacketData::NestedList(parse_packet_in_line(line, d)?))
+            } else {
+                Ok(PacketData::Integer(parse_token(
+                    DAY,
+                    line,
+                    d,
+                    "an integer or a list",
+                )?))
+            }
+        })
+        .collect()
+}
+
+pub fn parse_input_as_pairs(input: &str) -> Result<Vec<(Vec<PacketData>, Vec<PacketData>)>> {
+    let input = &normalize_input(input);
+    let mut packet_pairs = Vec::new();
+    for (line_offset, packet_pair) in split_blocks(input) {
+        let lines: Vec<&str> = packet_pair.lines().collect();
+        if lines.len() != 2 {
+            let last_line = lines.last().copied().unwrap_or(packet_pair);
+            return Err(ParseError::new(
+                DAY,
+                last_line,
+                end_of_line(last_line),
+                "a pair of packets",
+            )
+            .shift_lines(line_offset + lines.len().saturating_sub(1)));
+        }
+        let left = parse_packet(lines[0]).map_err(|e| e.shift_lines(line_offset))?;
+        let right = parse_packet(lines[1]).map_err(|e| e.shift_lines(line_offset + 1))?;
+        packet_pairs.push((left, right));
+    }
+    Ok(packet_pairs)
+}
+
+pub fn parse_inputs_individually(input: &str) -> Result<Vec<Vec<PacketData>>> {
+    let input = &normalize_input(input);
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_packet(line).map_err(|e| e.shift_lines(i)))
+        .collect()
+}
+
+pub fn get_packet_ordering(left: &[PacketData], right: &[PacketData]) -> Ordering {
+    let mut left_iter = left.iter();
+    let mut right_iter = right.iter();
+
+    for _ in 0..left.len().max(right.len()) {
+        match (left_iter.next(), right_iter.next()) {
+            (Some(l), Some(r)) => {
+                let comparison = match (l, r) {
+                    (PacketData::Integer(integer_left), PacketData::Integer(integer_right)) => {
+                        integer_left.cmp(integer_right)
+                    }
+                    (PacketData::Integer(_), PacketData::NestedList(right_list)) => {
+                        get_packet_ordering(std::slice::from_ref(l), right_list)
+                    }
+                    (PacketData::NestedList(left_list), PacketData::Integer(_)) => {
+                        get_packet_ordering(left_list, std::slice::from_ref(r))
+                    }
+                    (PacketData::NestedList(left_list), PacketData::NestedList(right_list)) => {
+                        get_packet_ordering(left_list, right_list)
+                    }
+                };
+
+                if comparison != Ordering::Equal {
+                    return comparison;
+                }
+            }
+            (Some(_), None) => {
+                return Ordering::Greater;
+            }
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => (),
+        }
+    }
+
+    Ordering::Equal
+}
+
+pub struct DistressSignal;
+
+impl Solution for DistressSignal {
+    type Input = Vec<(Vec<PacketData>, Vec<PacketData>)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input_as_pairs(input)
+    }
+
+    fn part1(packet_pairs: &Self::Input) -> SolveResult<Answer> {
+        Ok(packet_pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| get_packet_ordering(left, right) == Ordering::Less)
+            .map(|(i, _)| i + 1)
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(packet_pairs: &Self::Input) -> SolveResult<Answer> {
+        let mut packets = packet_pairs
+            .iter()
+            .flat_map(|(left, right)| [left.clone(), right.clone()])
+            .collect::<Vec<Vec<PacketData>>>();
+        let separator_1 = vec![PacketData::NestedList(vec![PacketData::Integer(2)])];
+        packets.push(separator_1.clone());
+        let separator_2 = vec![PacketData::NestedList(vec![PacketData::Integer(6)])];
+        packets.push(separator_2.clone());
+        packets.sort_by(|left, right| get_packet_ordering(left, right));
+        Ok(packets
+            .iter()
+            .cloned()
+            .enumerate()
+            .filter(|(_, packet)| packet == &separator_1 || packet == &separator_2)
+            .map(|(i, _)| i + 1)
+            .product::<usize>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec;
+
+    #[test]
+    fn test_packet_parsing() {
+        assert_eq!(parse_packet("[]").unwrap(), Vec::new());
+        assert_eq!(parse_packet("[1]").unwrap(), vec![PacketData::Integer(1)]);
+        assert_eq!(
+            parse_packet("[1,2,3]").unwrap(),
+            vec![
+                PacketData::Integer(1),
+                PacketData::Integer(2),
+                PacketData::Integer(3),
+            ]
+        );
+        assert_eq!(
+            parse_packet("[1,[],3]").unwrap(),
+            vec![
+                PacketData::Integer(1),
+                PacketData::NestedList(Vec::new()),
+                PacketData::Integer(3),
+            ]
+        );
+        assert_eq!(
+            parse_packet("[[1,[2,[]]],3]").unwrap(),
+            vec![
+                PacketData::NestedList(vec![
+                    PacketData::Integer(1),
+                    PacketData::NestedList(vec![
+                        PacketData::Integer(2),
+                        PacketData::NestedList(Vec::new()),
+                    ]),
+                ]),
+                PacketData::Integer(3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_packet_parsing_errors() {
+        let error = parse_packet("[1,2").unwrap_err();
+        assert_eq!(error.expected, "a list in brackets");
+        let error = parse_packet("[1,[2]").unwrap_err();
+        assert_eq!(error.column, 6);
+        assert_eq!(error.expected, "a closing `]`");
+        let error = parse_packet("[1],[2]").unwrap_err();
+        assert_eq!(error.column, 3);
+        let error = parse_packet("[1,,2]").unwrap_err();
+        assert_eq!(error.column, 4);
+        assert_eq!(error.text, "");
+        let error = parse_packet("[1,[a]]").unwrap_err();
+        assert_eq!(error.column, 5);
+        assert_eq!(error.text, "a");
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input_as_pairs("[1]\n[2]\n\n[3]\n[4,x]").unwrap_err();
+        assert_eq!(error.line, 5);
+        assert_eq!(error.column, 4);
+        let error = parse_input_as_pairs("[1]\n[2]\n\n[3]").unwrap_err();
+        assert_eq!(error.line, 4);
+        assert_eq!(error.expected, "a pair of packets");
+        let error = parse_inputs_individually("[1]\n[2]\n\n[3]\n[4,x]").unwrap_err();
+        assert_eq!(error.line, 5);
+    }
+
+    #[test]
+    fn test_input_parsing_as_pairs() {
+        let packet_pairs = parse_input_as_pairs(
+            "[[4,4],4,4]
+            [[4,4],4,4,4]
+
+            [1,[2,[3,[4,[5,6,7]]]],8,9]
+            [1,[2,[3,[4,[5,6,0]]]],8,9]",
+        )
+        .unwrap();
+        assert_eq!(
+            packet_pairs,
+            vec![
+                (
+                    vec![
+                        PacketData::NestedList(vec![
+                            PacketData::Integer(4),
+                            PacketData::Integer(4),
+                        ]),
+                        PacketData::Integer(4),
+                        PacketData::Integer(4),
+                    ],
+                    vec![
+                        PacketData::NestedList(vec![
+                            PacketData::Integer(4),
+                            PacketData::Integer(4),
+                        ]),
+                        PacketData::Integer(4),
+                        PacketData::Integer(4),
+                        PacketData::Integer(4),
+                    ]
+                ),
+                (
+                    vec![
+                        PacketData::Integer(1),
+                        PacketData::NestedList(vec![
+                            PacketData::Integer(2),
+                            PacketData::NestedList(vec![
+                                PacketData::Integer(3),
+                                PacketData::NestedList(vec![
+                                    PacketData::Integer(4),
+                                    PacketData::NestedList(vec![
+                                        PacketData::Integer(5),
+                                        PacketData::Integer(6),
+                                        PacketData::Integer(7),
+                                    ]),
+                                ]),
+                            ]),
+                        ]),
+                        PacketData::Integer(8),
+                        PacketData::Integer(9),
+                    ],
+                    vec![
+                        PacketData::Integer(1),
+                        PacketData::NestedList(vec![
+                            PacketData::Integer(2),
+                            PacketData::NestedList(vec![
+                                PacketData::Integer(3),
+                                PacketData::NestedList(vec![
+                                    PacketData::Integer(4),
+                                    PacketData::NestedList(vec![
+                                        PacketData::Integer(5),
+                                        PacketData::Integer(6),
+                                        PacketData::Integer(0),
+                                    ]),
+                                ]),
+                            ]),
+                        ]),
+                        PacketData::Integer(8),
+                        PacketData::Integer(9),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_parsing_individually() {
+        let packets = parse_inputs_individually(
+            "[[4,4],4,4]
+            [[4,4],4,4,4]
+
+            [1,[2,[3,[4,[5,6,7]]]],8,9]
+            [1,[2,[3,[4,[5,6,0]]]],8,9]",
+        )
+        .unwrap();
+        assert_eq!(
+            packets,
+            vec![
+                vec![
+                    PacketData::NestedList(vec![PacketData::Integer(4), PacketData::Integer(4),]),
+                    PacketData::Integer(4),
+                    PacketData::Integer(4),
+                ],
+                vec![
+                    PacketData::NestedList(vec![PacketData::Integer(4), PacketData::Integer(4),]),
+                    PacketData::Integer(4),
+                    PacketData::Integer(4),
+                    PacketData::Integer(4),
+                ],
+                vec![
+                    PacketData::Integer(1),
+                    PacketData::NestedList(vec![
+                        PacketData::Integer(2),
+                        PacketData::NestedList(vec![
+                            PacketData::Integer(3),
+                            PacketData::NestedList(vec![
+                                PacketData::Integer(4),
+                                PacketData::NestedList(vec![
+                                    PacketData::Integer(5),
+                                    PacketData::Integer(6),
+                                    PacketData::Integer(7),
+                                ]),
+                            ]),
+                        ]),
+                    ]),
+                    PacketData::Integer(8),
+                    PacketData::Integer(9),
+                ],
+                vec![
+                    PacketData::Integer(1),
+                    PacketData::NestedList(vec![
+                        PacketData::Integer(2),
+                        PacketData::NestedList(vec![
+                            PacketData::Integer(3),
+                            PacketData::NestedList(vec![
+                                PacketData::Integer(4),
+                                PacketData::NestedList(vec![
+                                    PacketData::Integer(5),
+                                    PacketData::Integer(6),
+                                    PacketData::Integer(0),
+                                ]),
+                            ]),
+                        ]),
+                    ]),
+                    PacketData::Integer(8),
+                    PacketData::Integer(9),
+                ]
+            ]
+        )
+    }
+
+    #[test]
+    fn test_packet_ordering() {
+        assert_eq!(
+            get_packet_ordering(
+                &parse_packet("[1,1,3,1,1]").unwrap(),
+                &parse_packet("[1,1,5,1,1]").unwrap()
+            ),
+            Ordering::Less
+        );
+        assert_eq!(
+            get_packet_ordering(
+                &parse_packet("[[1],[2,3,4]]").unwrap(),
+                &parse_packet("[[1],4]").unwrap()
+            ),
+            Ordering::Less
+        );
+        assert_eq!(
+            get_packet_ordering(
+                &parse_packet("[9]").unwrap(),
+                &parse_packet("[[8,7,6]]").unwrap()
+            ),
+            Ordering::Greater
+        );
+        assert_eq!(
+            get_packet_ordering(
+                &parse_packet("[[4,4],4,4]").unwrap(),
+                &parse_packet("[[4,4],4,4,4]").unwrap()
+            ),
+            Ordering::Less
+        );
+        assert_eq!(
+            get_packet_ordering(
+                &parse_packet("[7,7,7,7]").unwrap(),
+                &parse_packet("[7,7,7]").unwrap()
+            ),
+            Ordering::Greater
+        );
+        assert_eq!(
+            get_packet_ordering(&parse_packet("[]").unwrap(), &parse_packet("[3]").unwrap()),
+            Ordering::Less
+        );
+        assert_eq!(
+            get_packet_ordering(
+                &parse_packet("[[[]]]").unwrap(),
+                &parse_packet("[[]]").unwrap()
+            ),
+            Ordering::Greater
+        );
+        assert_eq!(
+            get_packet_ordering(
+                &parse_packet("[1,[2,[3,[4,[5,6,7]]]],8,9]").unwrap(),
+                &parse_packet("[1,[2,[3,[4,[5,6,0]]]],8,9]").unwrap(),
+            ),
+            Ordering::Greater
+        );
+    }
+}
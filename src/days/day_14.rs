@@ -0,0 +1,286 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::geometry::Direction;
+use crate::grid::{Grid, Point};
+use crate::parsing::{end_of_line, normalize_input, parse_token, split_token};
+use crate::solution::{Answer, Solution};
+use std::fmt;
+
+const DAY: u8 = 14;
+const CAVE_WIDTH: usize = 1000;
+const SAND_SOURCE_X: usize = 500;
+// Sand tries to fall straight down first, then diagonally to the left and to the right
+const FALL_DIRECTIONS: [Direction; 3] =
+    [Direction::Down, Direction::DownLeft, Direction::DownRight];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(c: char) -> std::result::Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Tile::Air),
+            '#' => Ok(Tile::Rock),
+            'o' => Ok(Tile::Sand),
+            _ => Err(c),
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let tile = match self {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        };
+        write!(f, "{}", tile)
+    }
+}
+
+pub fn parse_path_point(line: &str, path_point: &str) -> Result<Point> {
+    let (x, y) = split_token(DAY, line, path_point, ",")?;
+    let x = parse_token(DAY, line, x, "an x coordinate")?;
+    if x >= CAVE_WIDTH {
+        let expected = format!("an x coordinate below {}", CAVE_WIDTH);
+        return Err(ParseError::new(DAY, line, path_point, &expected));
+    }
+    let y = parse_token(DAY, line, y, "a y coordinate")?;
+    Ok(Point::new(x, y))
+}
+
+pub fn parse_path_line(line: &str) -> Result<Vec<Point>> {
+    line.trim()
+        .split(" -> ")
+        .map(|path_point| parse_path_point(line, path_point))
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<Point>>> {
+    let input = &normalize_input(input);
+    if input.trim().is_empty() {
+        return Err(ParseError::new(
+            DAY,
+            input,
+            end_of_line(input),
+            "a rock path",
+        ));
+    }
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_path_line(line).map_err(|e| e.shift_lines(i)))
+        .collect()
+}
+
+pub fn create_cave_system(paths: Vec<Vec<Point>>) -> Grid<Tile> {
+    let height = paths
+        .iter()
+        .map(|path_points| path_points.iter().map(|p| p.y).max().unwrap())
+        .max()
+        .unwrap();
+
+    let mut cave_system = Grid::new(CAVE_WIDTH, height + 1, Tile::Air);
+
+    for path in paths {
+        for path_segment in path.windows(2) {
+            let (from, to) = (path_segment[0], path_segment[1]);
+            for y in from.y.min(to.y)..=from.y.max(to.y) {
+                for x in from.x.min(to.x)..=from.x.max(to.x) {
+                    cave_system[Point::new(x, y)] = Tile::Rock;
+                }
+            }
+        }
+    }
+
+    cave_system
+}
+
+// Sand leaving the cave at the bottom or the sides has no rock below it and falls forever
+pub fn get_final_sand_position(sand_source_index: usize, cave_spec: &Grid<Tile>) -> Option<Point> {
+    let mut sand = Point::new(sand_source_index, 0);
+
+    'falling: loop {
+        for direction in FALL_DIRECTIONS {
+            let next_point = sand.checked_add_signed(direction.get_offset())?;
+            match cave_spec.get(next_point)? {
+                Tile::Air => {
+                    sand = next_point;
+                    continue 'falling;
+                }
+                Tile::Rock | Tile::Sand => {}
+            }
+        }
+        return Some(sand);
+    }
+}
+
+// The sand piles up on the floor at most as far to each side as the floor is deep, so the cave is
+// widened by that depth on both sides and the sand source moves along
+pub fn add_cave_floor(cave_system: &Grid<Tile>) -> (Grid<Tile>, usize) {
+    let floor_depth = cave_system.height() + 1;
+    let margin = vec![Tile::Air; floor_depth];
+    let mut rows = cave_system
+        .rows()
+        .map(|row| [&margin, row, &margin].concat())
+        .collect::<Vec<Vec<Tile>>>();
+    let width = cave_system.width() + 2 * floor_depth;
+    rows.push(vec![Tile::Air; width]);
+    rows.push(vec![Tile::Rock; width]);
+    (Grid::from_rows(rows), SAND_SOURCE_X + floor_depth)
+}
+
+pub fn fill_with_sand(sand_source_index: usize, cave_system: &Grid<Tile>) -> usize {
+    let mut sand_counter = 0;
+    let mut cave_system = cave_system.clone();
+
+    while let Some(sand) = get_final_sand_position(sand_source_index, &cave_system) {
+        sand_counter += 1;
+        cave_system[sand] = Tile::Sand;
+        if sand == Point::new(sand_source_index, 0) {
+            break;
+        }
+    }
+
+    sand_counter
+}
+
+pub struct RegolithReservoir;
+
+impl Solution for RegolithReservoir {
+    type Input = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(create_cave_system(parse_input(input)?))
+    }
+
+    fn part1(cave_system: &Self::Input) -> SolveResult<Answer> {
+        Ok(fill_with_sand(SAND_SOURCE_X, cave_system).into())
+    }
+
+    fn part2(cave_system: &Self::Input) -> SolveResult<Answer> {
+        let (cave_with_floor, sand_source_index) = add_cave_floor(cave_system);
+        Ok(fill_with_sand(sand_source_index, &cave_with_floor).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_parsing() {
+        let paths = parse_input("0,1 -> 2,3 -> 4,5\n6,7 -> 8,9").unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                vec![Point::new(0, 1), Point::new(2, 3), Point::new(4, 5)],
+                vec![Point::new(6, 7), Point::new(8, 9)]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("0,1 -> 2,3\n6,7 -> 8;9").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 8);
+        assert_eq!(error.expected, "a text containing `,`");
+
+        let error = parse_input("0,1 -> 2,x").unwrap_err();
+        assert_eq!(error.column, 10);
+        assert_eq!(error.expected, "a y coordinate");
+
+        let error = parse_input("0,1 -> 1000,3").unwrap_err();
+        assert_eq!(error.column, 8);
+        assert_eq!(error.text, "1000,3");
+
+        assert!(parse_input("").is_err());
+    }
+
+    #[test]
+    fn test_create_cave_system() {
+        let paths = vec![
+            vec![Point::new(499, 3), Point::new(501, 3), Point::new(501, 1)],
+            vec![Point::new(500, 1), Point::new(500, 3)],
+        ];
+        let cave_system = create_cave_system(paths);
+
+        assert_eq!((cave_system.width(), cave_system.height()), (CAVE_WIDTH, 4));
+        for (x, y) in [
+            (499, 3),
+            (500, 3),
+            (501, 3),
+            (501, 2),
+            (501, 1),
+            (500, 1),
+            (500, 2),
+        ] {
+            assert_eq!(cave_system[Point::new(x, y)], Tile::Rock);
+        }
+        assert_eq!(cave_system[Point::new(499, 2)], Tile::Air);
+    }
+
+    #[test]
+    fn test_final_sand_position() {
+        let cave_system = "....
+            #...
+            ##..
+            ....
+            ....
+            ...#
+            ..##
+            ####"
+            .parse::<Grid<Tile>>()
+            .unwrap();
+        let sand_position = get_final_sand_position(2, &cave_system).unwrap();
+        assert_eq!(sand_position, Point::new(1, 6));
+    }
+
+    #[test]
+    fn test_fill_with_sand() {
+        let cave_system = "....#
+            ....#
+            ....#
+            .####"
+            .parse::<Grid<Tile>>()
+            .unwrap();
+        assert_eq!(fill_with_sand(3, &cave_system), 3);
+        // The sand is only added to a copy of the cave system
+        assert_eq!(cave_system.to_string(), "....#\n....#\n....#\n.####");
+    }
+
+    #[test]
+    fn test_sand_falls_off_the_cave_edges() {
+        let cave_system = "..\n#.\n##".parse::<Grid<Tile>>().unwrap();
+        assert_eq!(get_final_sand_position(0, &cave_system), None);
+        let cave_system = "..\n.#\n##".parse::<Grid<Tile>>().unwrap();
+        assert_eq!(get_final_sand_position(1, &cave_system), None);
+    }
+
+    #[test]
+    fn test_add_cave_floor() {
+        let cave_system = "...\n.#.".parse::<Grid<Tile>>().unwrap();
+        let (cave_with_floor, sand_source_index) = add_cave_floor(&cave_system);
+        assert_eq!(sand_source_index, SAND_SOURCE_X + 3);
+        assert_eq!(
+            cave_with_floor.to_string(),
+            ".........\n....#....\n.........\n#########"
+        );
+    }
+
+    #[test]
+    fn test_floor_beyond_the_rocks() {
+        // The pile on the floor reaches past the right end of the cave system
+        assert_eq!(
+            RegolithReservoir::solve_part_2("999,1 -> 999,1"),
+            Ok(Answer::Integer(9))
+        );
+    }
+}
@@ -0,0 +1,358 @@
+use crate::error::{ParseError, Result, SolveError, SolveResult};
+use crate::parsing::{normalize_input, parse_token, split_token};
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+const DAY: u8 = 15;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Sensor {
+    pub position: (i64, i64),
+    pub closest_beacon: (i64, i64),
+}
+
+impl Sensor {
+    pub fn get_range(&self) -> i64 {
+        get_manhattan_distance(self.position, self.closest_beacon)
+    }
+
+    pub fn covers(&self, position: (i64, i64)) -> bool {
+        get_manhattan_distance(self.position, position) <= self.get_range()
+    }
+
+    pub fn get_covered_interval(&self, row: i64) -> Option<(i64, i64)> {
+        let remaining_range = self.get_range() - (row - self.position.1).abs();
+        if remaining_range < 0 {
+            None
+        } else {
+            Some((
+                self.position.0 - remaining_range,
+                self.position.0 + remaining_range,
+            ))
+        }
+    }
+}
+
+pub fn get_manhattan_distance(a: (i64, i64), b: (i64, i64)) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+fn parse_coordinate(line: &str, coordinate: &str, prefix: &str) -> Result<i64> {
+    match coordinate.strip_prefix(prefix) {
+        Some(value) => parse_token(DAY, line, value, "an integer coordinate"),
+        None => Err(ParseError::new(
+            DAY,
+            line,
+            coordinate,
+            &format!("a coordinate starting with `{}`", prefix),
+        )),
+    }
+}
+
+pub fn parse_position(line: &str, position: &str) -> Result<(i64, i64)> {
+    let (x, y) = split_token(DAY, line, position, ", ")?;
+    Ok((
+        parse_coordinate(line, x, "x=")?,
+        parse_coordinate(line, y, "y=")?,
+    ))
+}
+
+pub fn parse_sensor_line(line: &str) -> Result<Sensor> {
+    let sensor_line = line.trim();
+    let Some(positions) = sensor_line.strip_prefix("Sensor at ") else {
+        return Err(ParseError::new(
+            DAY,
+            line,
+            sensor_line,
+            "a line starting with `Sensor at `",
+        ));
+    };
+    let (sensor_position, beacon_position) =
+        split_token(DAY, line, positions, ": closest beacon is at ")?;
+    Ok(Sensor {
+        position: parse_position(line, sensor_position)?,
+        closest_beacon: parse_position(line, beacon_position)?,
+    })
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Sensor>> {
+    let input = &normalize_input(input);
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_sensor_line(line).map_err(|e| e.shift_lines(i)))
+        .collect()
+}
+
+// Merges overlapping and adjacent inclusive intervals into sorted, disjoint intervals
+pub fn merge_intervals(mut intervals: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    intervals.sort();
+    let mut merged_intervals: Vec<(i64, i64)> = Vec::new();
+    for (start, end) in intervals {
+        match merged_intervals.last_mut() {
+            Some(last_interval) if start <= last_interval.1 + 1 => {
+                last_interval.1 = last_interval.1.max(end);
+            }
+            _ => merged_intervals.push((start, end)),
+        }
+    }
+    merged_intervals
+}
+
+pub fn get_covered_intervals(sensors: &[Sensor], row: i64) -> Vec<(i64, i64)> {
+    merge_intervals(
+        sensors
+            .iter()
+            .filter_map(|sensor| sensor.get_covered_interval(row))
+            .collect(),
+    )
+}
+
+pub fn count_positions_without_beacon(sensors: &[Sensor], row: i64) -> i64 {
+    let covered_positions = get_covered_intervals(sensors, row)
+        .iter()
+        .map(|(start, end)| end - start + 1)
+        .sum::<i64>();
+    // Every beacon is covered by the sensor it is closest to
+    let beacons_in_row = sensors
+        .iter()
+        .map(|sensor| sensor.closest_beacon)
+        .filter(|beacon| beacon.1 == row)
+        .collect::<HashSet<(i64, i64)>>();
+    covered_positions - beacons_in_row.len() as i64
+}
+
+pub fn is_uncovered(sensors: &[Sensor], position: (i64, i64), bound: i64) -> bool {
+    (0..=bound).contains(&position.0)
+        && (0..=bound).contains(&position.1)
+        && !sensors.iter().any(|sensor| sensor.covers(position))
+}
+
+// A single uncovered position has covered neighbours, so it lies just outside the diamonds of the
+// surrounding sensors, i.e. on a crossing of their diagonal edges or where an edge meets the border
+pub fn find_uncovered_position_on_diamond_edges(
+    sensors: &[Sensor],
+    bound: i64,
+) -> Option<(i64, i64)> {
+    let mut ascending_edges = HashSet::new();
+    let mut descending_edges = HashSet::new();
+    for sensor in sensors {
+        let (x, y) = sensor.position;
+        let distance = sensor.get_range() + 1;
+        ascending_edges.extend([y - x - distance, y - x + distance]);
+        descending_edges.extend([y + x - distance, y + x + distance]);
+    }
+
+    let mut candidates = vec![(0, 0), (0, bound), (bound, 0), (bound, bound)];
+    for a in &ascending_edges {
+        candidates.extend([(0, *a), (bound, bound + a), (-a, 0), (bound - a, bound)]);
+        for b in &descending_edges {
+            if (b - a) % 2 == 0 {
+                candidates.push(((b - a) / 2, (a + b) / 2));
+            }
+        }
+    }
+    for b in &descending_edges {
+        candidates.extend([(0, *b), (bound, b - bound), (*b, 0), (b - bound, bound)]);
+    }
+
+    candidates
+        .into_iter()
+        .find(|candidate| is_uncovered(sensors, *candidate, bound))
+}
+
+pub fn find_uncovered_position_by_row_sweep(sensors: &[Sensor], bound: i64) -> Option<(i64, i64)> {
+    for y in 0..=bound {
+        let mut x = 0;
+        for (start, end) in get_covered_intervals(sensors, y) {
+            if start > x {
+                break;
+            }
+            x = x.max(end + 1);
+        }
+        if x <= bound {
+            return Some((x, y));
+        }
+    }
+    None
+}
+
+pub fn find_distress_beacon(sensors: &[Sensor], bound: i64) -> Option<(i64, i64)> {
+    find_uncovered_position_on_diamond_edges(sensors, bound)
+        .or_else(|| find_uncovered_position_by_row_sweep(sensors, bound))
+}
+
+pub fn get_tuning_frequency(position: (i64, i64)) -> i64 {
+    position.0 * 4000000 + position.1
+}
+
+// The puzzle input is checked on row 2000000 and in the square up to 4000000, the example on row
+// 10 and in the square up to 20
+pub struct BeaconExclusionZone<const ROW: i64 = 2000000, const BOUND: i64 = 4000000>;
+
+impl<const ROW: i64, const BOUND: i64> Solution for BeaconExclusionZone<ROW, BOUND> {
+    type Input = Vec<Sensor>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(sensors: &Self::Input) -> SolveResult<Answer> {
+        Ok(count_positions_without_beacon(sensors, ROW).into())
+    }
+
+    fn part2(sensors: &Self::Input) -> SolveResult<Answer> {
+        match find_distress_beacon(sensors, BOUND) {
+            Some(position) => Ok(get_tuning_frequency(position).into()),
+            None => Err(SolveError::no_answer(
+                DAY,
+                2,
+                &format!("no distress beacon in the square up to {}", BOUND),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+        Sensor at x=9, y=16: closest beacon is at x=10, y=16
+        Sensor at x=13, y=2: closest beacon is at x=15, y=3
+        Sensor at x=12, y=14: closest beacon is at x=10, y=16
+        Sensor at x=10, y=20: closest beacon is at x=10, y=16
+        Sensor at x=14, y=17: closest beacon is at x=10, y=16
+        Sensor at x=8, y=7: closest beacon is at x=2, y=10
+        Sensor at x=2, y=0: closest beacon is at x=2, y=10
+        Sensor at x=0, y=11: closest beacon is at x=2, y=10
+        Sensor at x=20, y=14: closest beacon is at x=25, y=17
+        Sensor at x=17, y=20: closest beacon is at x=21, y=22
+        Sensor at x=16, y=7: closest beacon is at x=15, y=3
+        Sensor at x=14, y=3: closest beacon is at x=15, y=3
+        Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    #[test]
+    fn test_sensor_line_parsing() {
+        assert_eq!(
+            parse_sensor_line("Sensor at x=2, y=18: closest beacon is at x=-2, y=15").unwrap(),
+            Sensor {
+                position: (2, 18),
+                closest_beacon: (-2, 15),
+            }
+        );
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=2, y=a: closest beacon is at x=1, y=1").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 18);
+        assert_eq!(error.text, "a");
+
+        let error =
+            parse_input("Sensor at x=2, z=18: closest beacon is at x=-2, y=15").unwrap_err();
+        assert_eq!(error.column, 16);
+        assert_eq!(error.expected, "a coordinate starting with `y=`");
+
+        let error = parse_input("Beacon at x=2, y=18").unwrap_err();
+        assert_eq!(error.expected, "a line starting with `Sensor at `");
+
+        let error = parse_input("Sensor at x=2, y=18").unwrap_err();
+        assert_eq!(
+            error.expected,
+            "a text containing `: closest beacon is at `"
+        );
+    }
+
+    #[test]
+    fn test_covered_interval() {
+        let sensor = Sensor {
+            position: (8, 7),
+            closest_beacon: (2, 10),
+        };
+        assert_eq!(sensor.get_range(), 9);
+        assert_eq!(sensor.get_covered_interval(7), Some((-1, 17)));
+        assert_eq!(sensor.get_covered_interval(10), Some((2, 14)));
+        assert_eq!(sensor.get_covered_interval(16), Some((8, 8)));
+        assert_eq!(sensor.get_covered_interval(17), None);
+        assert_eq!(sensor.get_covered_interval(-3), None);
+    }
+
+    #[test]
+    fn test_merge_intervals() {
+        assert_eq!(
+            merge_intervals(vec![(12, 12), (2, 14), (2, 2), (-2, 2), (16, 24), (14, 18)]),
+            vec![(-2, 24)]
+        );
+        assert_eq!(
+            merge_intervals(vec![(5, 6), (0, 2), (3, 3), (8, 9)]),
+            vec![(0, 3), (5, 6), (8, 9)]
+        );
+        assert_eq!(merge_intervals(Vec::new()), Vec::new());
+    }
+
+    #[test]
+    fn test_count_positions_without_beacon() {
+        let sensors = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(count_positions_without_beacon(&sensors, 10), 26);
+        assert_eq!(count_positions_without_beacon(&sensors, 9), 25);
+    }
+
+    #[test]
+    fn test_find_distress_beacon() {
+        let sensors = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(
+            find_uncovered_position_on_diamond_edges(&sensors, 20),
+            Some((14, 11))
+        );
+        assert_eq!(
+            find_uncovered_position_by_row_sweep(&sensors, 20),
+            Some((14, 11))
+        );
+        assert_eq!(get_tuning_frequency((14, 11)), 56000011);
+    }
+
+    #[test]
+    fn test_find_distress_beacon_at_the_border() {
+        // The sensor covers everything but the bottom right corner of the square
+        let sensors = vec![Sensor {
+            position: (0, 0),
+            closest_beacon: (0, 7),
+        }];
+        assert_eq!(
+            find_uncovered_position_on_diamond_edges(&sensors, 4),
+            Some((4, 4))
+        );
+        assert_eq!(
+            find_uncovered_position_by_row_sweep(&sensors, 4),
+            Some((4, 4))
+        );
+        assert_eq!(find_distress_beacon(&sensors, 3), None);
+    }
+
+    #[test]
+    fn test_configurable_row_and_bound() {
+        let sensors = BeaconExclusionZone::<10, 20>::parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(
+            BeaconExclusionZone::<10, 20>::part1(&sensors),
+            Ok(Answer::Integer(26))
+        );
+        assert_eq!(
+            BeaconExclusionZone::<10, 20>::part2(&sensors),
+            Ok(Answer::Integer(56000011))
+        );
+        assert_eq!(
+            <BeaconExclusionZone>::part1(&sensors),
+            Ok(Answer::Integer(0))
+        );
+        assert!(matches!(
+            BeaconExclusionZone::<10, 3>::part2(&sensors),
+            Err(SolveError::NoAnswer {
+                day: 15,
+                part: 2,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,445 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::parsing::{end_of_line, normalize_input, parse_token, split_token};
+use crate::solution::{Answer, Solution};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+const DAY: u8 = 16;
+const START_VALVE: &str = "AA";
+const MINUTES_ALONE: u32 = 30;
+const MINUTES_WITH_ELEPHANT: u32 = 26;
+const MAX_FLOWING_VALVES: usize = u64::BITS as usize;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Valve {
+    pub name: String,
+    pub flow_rate: u32,
+    pub tunnels: Vec<String>,
+}
+
+pub struct ValveGraph {
+    pub flow_rates: HashMap<String, u32>,
+    pub adjacency_list: HashMap<String, HashSet<String>>,
+}
+
+impl ValveGraph {
+    pub fn new(valves: &[Valve]) -> Self {
+        let mut flow_rates = HashMap::new();
+        let mut adjacency_list = HashMap::new();
+        for valve in valves {
+            flow_rates.insert(valve.name.clone(), valve.flow_rate);
+            adjacency_list.insert(
+                valve.name.clone(),
+                valve.tunnels.iter().cloned().collect::<HashSet<String>>(),
+            );
+        }
+
+        Self {
+            flow_rates,
+            adjacency_list,
+        }
+    }
+
+    // Every tunnel takes one minute, so a breadth first search gives the shortest travel times
+    pub fn get_distances_from<'a>(&'a self, valve: &'a str) -> HashMap<&'a str, u32> {
+        let mut distances = HashMap::from([(valve, 0)]);
+        let mut search_queue = VecDeque::from([valve]);
+        while let Some(current_valve) = search_queue.pop_front() {
+            let distance = distances[current_valve] + 1;
+            if let Some(neighbors) = self.adjacency_list.get(current_valve) {
+                for neighbor in neighbors {
+                    if !distances.contains_key(neighbor.as_str()) {
+                        distances.insert(neighbor, distance);
+                        search_queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        distances
+    }
+}
+
+// Only the valves with a non-zero flow rate are worth moving to, so the search runs on these valves
+// with the travel times between them, and a set of open valves fits into the bits of a u64
+pub struct CompressedValveGraph {
+    pub valves: Vec<String>,
+    pub flow_rates: Vec<u32>,
+    pub start_distances: Vec<u32>,
+    pub distances: Vec<Vec<u32>>,
+}
+
+impl CompressedValveGraph {
+    pub fn new(graph: &ValveGraph, start_valve: &str) -> Self {
+        let mut valves = graph
+            .flow_rates
+            .iter()
+            .filter(|(_, flow_rate)| **flow_rate > 0)
+            .map(|(valve, _)| valve.clone())
+            .collect::<Vec<String>>();
+        valves.sort();
+        let flow_rates = valves.iter().map(|valve| graph.flow_rates[valve]).collect();
+        // Unreachable valves get an infinite distance and are never opened
+        let get_distances = |from_valve: &str| {
+            let distances = graph.get_distances_from(from_valve);
+            valves
+                .iter()
+                .map(|valve| *distances.get(valve.as_str()).unwrap_or(&u32::MAX))
+                .collect::<Vec<u32>>()
+        };
+
+        Self {
+            start_distances: get_distances(start_valve),
+            distances: valves.iter().map(|valve| get_distances(valve)).collect(),
+            flow_rates,
+            valves,
+        }
+    }
+}
+
+fn parse_valve_name<'a>(line: &str, name: &'a str) -> Result<&'a str> {
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_uppercase()) {
+        Ok(name)
+    } else {
+        Err(ParseError::new(
+            DAY,
+            line,
+            name,
+            "a valve name of uppercase letters",
+        ))
+    }
+}
+
+pub fn parse_valve_line(line: &str) -> Result<Valve> {
+    let valve_line = line.trim();
+    let Some(valve_description) = valve_line.strip_prefix("Valve ") else {
+        return Err(ParseError::new(
+            DAY,
+            line,
+            valve_line,
+            "a line starting with `Valve `",
+        ));
+    };
+    let (name, flow_description) = split_token(DAY, line, valve_description, " has flow rate=")?;
+    let (flow_rate, tunnel_description) = split_token(DAY, line, flow_description, "; ")?;
+    let tunnels = ["tunnels lead to valves ", "tunnel leads to valve "]
+        .iter()
+        .find_map(|prefix| tunnel_description.strip_prefix(prefix))
+        .ok_or_else(|| {
+            ParseError::new(
+                DAY,
+                line,
+                tunnel_description,
+                "a text starting with `tunnels lead to valves ` or `tunnel leads to valve `",
+            )
+        })?;
+
+    Ok(Valve {
+        name: String::from(parse_valve_name(line, name)?),
+        flow_rate: parse_token(DAY, line, flow_rate, "a flow rate")?,
+        tunnels: tunnels
+            .split(", ")
+            .map(|tunnel| parse_valve_name(line, tunnel).map(String::from))
+            .collect::<Result<Vec<String>>>()?,
+    })
+}
+
+pub fn parse_input(input: &str) -> Result<ValveGraph> {
+    let input = &normalize_input(input);
+    let lines = input.lines().collect::<Vec<&str>>();
+    let valves = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse_valve_line(line).map_err(|e| e.shift_lines(i)))
+        .collect::<Result<Vec<Valve>>>()?;
+
+    let mut valve_names = HashSet::new();
+    let mut flowing_valves = 0;
+    for (i, (line, valve)) in lines.iter().zip(&valves).enumerate() {
+        if !valve_names.insert(valve.name.as_str()) {
+            let name = &line[line.find(&valve.name).unwrap()..][..valve.name.len()];
+            return Err(ParseError::new(DAY, line, name, "a new valve name").shift_lines(i));
+        }
+        if valve.flow_rate > 0 {
+            flowing_valves += 1;
+            if flowing_valves > MAX_FLOWING_VALVES {
+                let expected = format!(
+                    "at most {} valves with a non-zero flow rate",
+                    MAX_FLOWING_VALVES
+                );
+                return Err(ParseError::new(DAY, line, line.trim(), &expected).shift_lines(i));
+            }
+        }
+    }
+    for (i, (line, valve)) in lines.iter().zip(&valves).enumerate() {
+        if let Some(tunnel) = valve
+            .tunnels
+            .iter()
+            .find(|tunnel| !valve_names.contains(tunnel.as_str()))
+        {
+            let tunnel = &line[line.rfind(tunnel.as_str()).unwrap()..][..tunnel.len()];
+            return Err(
+                ParseError::new(DAY, line, tunnel, "a tunnel to an existing valve").shift_lines(i),
+            );
+        }
+    }
+    if !valve_names.contains(START_VALVE) {
+        let last_line = lines.last().copied().unwrap_or(input);
+        return Err(ParseError::new(
+            DAY,
+            last_line,
+            end_of_line(last_line),
+            &format!("a start valve `{}`", START_VALVE),
+        )
+        .shift_lines(lines.len().saturating_sub(1)));
+    }
+
+    Ok(ValveGraph::new(&valves))
+}
+
+fn collect_best_pressures(
+    graph: &CompressedValveGraph,
+    distances: &[u32],
+    time_left: u32,
+    open_valves: u64,
+    pressure: u32,
+    best_pressures: &mut HashMap<u64, u32>,
+) {
+    let best_pressure = best_pressures.entry(open_valves).or_insert(0);
+    *best_pressure = (*best_pressure).max(pressure);
+
+    for (valve, distance) in distances.iter().enumerate() {
+        // Moving to a valve and opening it has to leave time for the pressure to be released
+        if open_valves & (1 << valve) != 0 || distance.saturating_add(1) >= time_left {
+            continue;
+        }
+        let remaining_time = time_left - distance - 1;
+        collect_best_pressures(
+            graph,
+            &graph.distances[valve],
+            remaining_time,
+            open_valves | (1 << valve),
+            pressure + remaining_time * graph.flow_rates[valve],
+            best_pressures,
+        );
+    }
+}
+
+// Maps every set of valves one actor can open in time to the most pressure released by opening them
+pub fn get_best_pressures(graph: &CompressedValveGraph, minutes: u32) -> HashMap<u64, u32> {
+    let mut best_pressures = HashMap::new();
+    collect_best_pressures(
+        graph,
+        &graph.start_distances,
+        minutes,
+        0,
+        0,
+        &mut best_pressures,
+    );
+    best_pressures
+}
+
+pub fn get_max_pressure_release(graph: &CompressedValveGraph, minutes: u32) -> u32 {
+    get_best_pressures(graph, minutes)
+        .into_values()
+        .max()
+        .unwrap_or(0)
+}
+
+// Both actors work independently, so the best plan combines the best pressures of two disjoint
+// sets of valves, which are tried from the highest pressure down until no pair can be better
+pub fn get_max_pressure_release_with_elephant(graph: &CompressedValveGraph, minutes: u32) -> u32 {
+    let mut best_pressures = get_best_pressures(graph, minutes)
+        .into_iter()
+        .collect::<Vec<(u64, u32)>>();
+    best_pressures.sort_by_key(|(_, pressure)| Reverse(*pressure));
+
+    let mut max_pressure = 0;
+    for (i, (own_valves, own_pressure)) in best_pressures.iter().enumerate() {
+        if own_pressure * 2 <= max_pressure {
+            break;
+        }
+        for (elephant_valves, elephant_pressure) in &best_pressures[i..] {
+            if own_pressure + elephant_pressure <= max_pressure {
+                break;
+            }
+            if own_valves & elephant_valves == 0 {
+                max_pressure = own_pressure + elephant_pressure;
+            }
+        }
+    }
+    max_pressure
+}
+
+pub struct ProboscideaVolcanium;
+
+impl Solution for ProboscideaVolcanium {
+    type Input = ValveGraph;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(graph: &Self::Input) -> SolveResult<Answer> {
+        let compressed_graph = CompressedValveGraph::new(graph, START_VALVE);
+        Ok(get_max_pressure_release(&compressed_graph, MINUTES_ALONE).into())
+    }
+
+    fn part2(graph: &Self::Input) -> SolveResult<Answer> {
+        let compressed_graph = CompressedValveGraph::new(graph, START_VALVE);
+        Ok(get_max_pressure_release_with_elephant(&compressed_graph, MINUTES_WITH_ELEPHANT).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+        Valve BB has flow rate=13; tunnels lead to valves CC, AA
+        Valve CC has flow rate=2; tunnels lead to valves DD, BB
+        Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+        Valve EE has flow rate=3; tunnels lead to valves FF, DD
+        Valve FF has flow rate=0; tunnels lead to valves EE, GG
+        Valve GG has flow rate=0; tunnels lead to valves FF, HH
+        Valve HH has flow rate=22; tunnel leads to valve GG
+        Valve II has flow rate=0; tunnels lead to valves AA, JJ
+        Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    #[test]
+    fn test_valve_line_parsing() {
+        assert_eq!(
+            parse_valve_line("Valve AA has flow rate=0; tunnels lead to valves DD, II, BB")
+                .unwrap(),
+            Valve {
+                name: String::from("AA"),
+                flow_rate: 0,
+                tunnels: vec![String::from("DD"), String::from("II"), String::from("BB")],
+            }
+        );
+        assert_eq!(
+            parse_valve_line("Valve HH has flow rate=22; tunnel leads to valve GG").unwrap(),
+            Valve {
+                name: String::from("HH"),
+                flow_rate: 22,
+                tunnels: vec![String::from("GG")],
+            }
+        );
+    }
+
+    #[test]
+    fn test_input_parsing() {
+        let graph = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(graph.flow_rates.len(), 10);
+        assert_eq!(graph.flow_rates["DD"], 20);
+        assert_eq!(
+            graph.adjacency_list["DD"],
+            HashSet::from([String::from("CC"), String::from("AA"), String::from("EE")])
+        );
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("Valve AA has flow rate=x; tunnel leads to valve AA")
+            .err()
+            .unwrap();
+        assert_eq!(error.column, 24);
+        assert_eq!(error.expected, "a flow rate");
+
+        let error = parse_input("Valve AA has flow rate=0; tunnels lead to valves BB, cc")
+            .err()
+            .unwrap();
+        assert_eq!(error.column, 54);
+
+        let error = parse_input(
+            "Valve AA has flow rate=0; tunnel leads to valve BB
+            Valve BB has flow rate=1; tunnels lead to valves AA, CC",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 66);
+        assert_eq!(error.expected, "a tunnel to an existing valve");
+
+        let error = parse_input(
+            "Valve AA has flow rate=0; tunnel leads to valve AA
+            Valve AA has flow rate=1; tunnel leads to valve AA",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 19);
+
+        let error = parse_input("Valve BB has flow rate=1; tunnel leads to valve BB")
+            .err()
+            .unwrap();
+        assert_eq!(error.expected, "a start valve `AA`");
+    }
+
+    #[test]
+    fn test_distances() {
+        let graph = parse_input(EXAMPLE_INPUT).unwrap();
+        let distances = graph.get_distances_from("AA");
+        assert_eq!(distances.len(), 10);
+        assert_eq!(distances["AA"], 0);
+        assert_eq!(distances["DD"], 1);
+        assert_eq!(distances["EE"], 2);
+        assert_eq!(distances["HH"], 5);
+    }
+
+    #[test]
+    fn test_graph_compression() {
+        let graph = parse_input(EXAMPLE_INPUT).unwrap();
+        let compressed_graph = CompressedValveGraph::new(&graph, START_VALVE);
+        assert_eq!(
+            compressed_graph.valves,
+            vec!["BB", "CC", "DD", "EE", "HH", "JJ"]
+        );
+        assert_eq!(compressed_graph.flow_rates, vec![13, 2, 20, 3, 22, 21]);
+        assert_eq!(compressed_graph.start_distances, vec![1, 2, 1, 2, 5, 2]);
+        assert_eq!(compressed_graph.distances[4], vec![6, 5, 4, 3, 0, 7]);
+    }
+
+    #[test]
+    fn test_unreachable_valves() {
+        let graph = parse_input(
+            "Valve AA has flow rate=0; tunnel leads to valve BB
+            Valve BB has flow rate=5; tunnel leads to valve AA
+            Valve CC has flow rate=100; tunnel leads to valve CC",
+        )
+        .unwrap();
+        let compressed_graph = CompressedValveGraph::new(&graph, START_VALVE);
+        assert_eq!(compressed_graph.start_distances, vec![1, u32::MAX]);
+        assert_eq!(get_max_pressure_release(&compressed_graph, 30), 28 * 5);
+        assert_eq!(get_max_pressure_release(&compressed_graph, 2), 0);
+    }
+
+    #[test]
+    fn test_max_pressure_release() {
+        let graph = parse_input(EXAMPLE_INPUT).unwrap();
+        let compressed_graph = CompressedValveGraph::new(&graph, START_VALVE);
+        assert_eq!(get_max_pressure_release(&compressed_graph, 30), 1651);
+        assert_eq!(get_max_pressure_release(&compressed_graph, 0), 0);
+    }
+
+    #[test]
+    fn test_max_pressure_release_with_elephant() {
+        let graph = parse_input(EXAMPLE_INPUT).unwrap();
+        let compressed_graph = CompressedValveGraph::new(&graph, START_VALVE);
+        assert_eq!(
+            get_max_pressure_release_with_elephant(&compressed_graph, 26),
+            1707
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(
+            ProboscideaVolcanium::solve_part_1(EXAMPLE_INPUT).unwrap(),
+            Answer::Integer(1651)
+        );
+        assert_eq!(
+            ProboscideaVolcanium::solve_part_2(EXAMPLE_INPUT).unwrap(),
+            Answer::Integer(1707)
+        );
+    }
+}
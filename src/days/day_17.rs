@@ -0,0 +1,325 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::parsing::{end_of_line, normalize_input};
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+const DAY: u8 = 17;
+const CHAMBER_WIDTH: usize = 7;
+const ROCK_COUNT: u64 = 2022;
+const LARGE_ROCK_COUNT: u64 = 1000000000000;
+
+// Every row of the chamber and of a rock is a bitmask, with the leftmost column in the highest of
+// the seven bits, and the rocks are listed bottom row first as they appear two units from the wall
+const ROCKS: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+const LEFT_WALL: u8 = 1 << (CHAMBER_WIDTH - 1);
+const RIGHT_WALL: u8 = 1;
+// Rocks don't fall further below the top of the tower, so deeper columns count as equally deep
+const MAX_SURFACE_DEPTH: usize = 64;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+impl Jet {
+    // Returns the rock pushed by the jet, or None if a row would be pushed into a wall
+    pub fn push(&self, rock: &[u8]) -> Option<Vec<u8>> {
+        match self {
+            Jet::Left if rock.iter().all(|row| row & LEFT_WALL == 0) => {
+                Some(rock.iter().map(|row| row << 1).collect())
+            }
+            Jet::Right if rock.iter().all(|row| row & RIGHT_WALL == 0) => {
+                Some(rock.iter().map(|row| row >> 1).collect())
+            }
+            _ => None,
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Jet>> {
+    let input = &normalize_input(input);
+    let mut lines = input.lines();
+    let jet_line = lines.next().unwrap_or(input);
+    if let Some(line) = lines.next() {
+        return Err(ParseError::new(DAY, line, line, "the end of the input").shift_lines(1));
+    }
+    if jet_line.trim().is_empty() {
+        return Err(ParseError::new(
+            DAY,
+            jet_line,
+            end_of_line(jet_line),
+            "a jet pattern of `<` and `>`",
+        ));
+    }
+
+    jet_line
+        .char_indices()
+        .map(|(i, jet)| match jet {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(ParseError::new(
+                DAY,
+                jet_line,
+                &jet_line[i..i + jet.len_utf8()],
+                "a jet `<` or `>`",
+            )),
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Chamber {
+    pub rows: Vec<u8>,
+    pub rock_index: usize,
+    pub jet_index: usize,
+}
+
+impl Chamber {
+    pub fn get_height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn collides(&self, rock: &[u8], bottom: usize) -> bool {
+        rock.iter().enumerate().any(|(i, row)| {
+            self.rows
+                .get(bottom + i)
+                .is_some_and(|chamber_row| chamber_row & row != 0)
+        })
+    }
+
+    pub fn drop_rock(&mut self, jets: &[Jet]) {
+        let mut rock = ROCKS[self.rock_index].to_vec();
+        self.rock_index = (self.rock_index + 1) % ROCKS.len();
+        let mut bottom = self.get_height() + 3;
+        loop {
+            let jet = jets[self.jet_index];
+            self.jet_index = (self.jet_index + 1) % jets.len();
+            if let Some(pushed_rock) = jet.push(&rock) {
+                if !self.collides(&pushed_rock, bottom) {
+                    rock = pushed_rock;
+                }
+            }
+            if bottom == 0 || self.collides(&rock, bottom - 1) {
+                break;
+            }
+            bottom -= 1;
+        }
+
+        for (i, row) in rock.into_iter().enumerate() {
+            match self.rows.get_mut(bottom + i) {
+                Some(chamber_row) => *chamber_row |= row,
+                None => self.rows.push(row),
+            }
+        }
+    }
+
+    // The depth of the highest rock in each column below the top of the tower, which together with
+    // the next rock and jet decides how the tower grows from here
+    pub fn get_surface_profile(&self) -> [usize; CHAMBER_WIDTH] {
+        let mut profile = [self.get_height().min(MAX_SURFACE_DEPTH); CHAMBER_WIDTH];
+        for (x, depth) in profile.iter_mut().enumerate() {
+            let column = LEFT_WALL >> x;
+            if let Some(row_depth) = self
+                .rows
+                .iter()
+                .rev()
+                .take(MAX_SURFACE_DEPTH)
+                .position(|row| row & column != 0)
+            {
+                *depth = row_depth;
+            }
+        }
+        profile
+    }
+
+    pub fn render_top_rows(&self, row_count: usize) -> String {
+        let mut rendering = String::new();
+        for row in self.rows.iter().rev().take(row_count) {
+            rendering.push('|');
+            for x in 0..CHAMBER_WIDTH {
+                let is_rock = row & (LEFT_WALL >> x) != 0;
+                rendering.push(if is_rock { '#' } else { '.' });
+            }
+            rendering.push_str("|\n");
+        }
+        if row_count >= self.get_height() {
+            rendering.push('+');
+            rendering.push_str(&"-".repeat(CHAMBER_WIDTH));
+            rendering.push_str("+\n");
+        }
+        rendering
+    }
+}
+
+pub fn simulate_rocks(jets: &[Jet], rock_count: usize) -> Chamber {
+    let mut chamber = Chamber::default();
+    for _ in 0..rock_count {
+        chamber.drop_rock(jets);
+    }
+    chamber
+}
+
+// Once the same rock and jet meet the same surface again the tower repeats, so the whole cycles
+// are skipped and only the remaining rocks are simulated
+pub fn get_tower_height(jets: &[Jet], rock_count: u64) -> u64 {
+    let mut chamber = Chamber::default();
+    let mut seen_states = HashMap::new();
+    let mut dropped_rocks = 0;
+    let mut skipped_height = None;
+    while dropped_rocks < rock_count {
+        chamber.drop_rock(jets);
+        dropped_rocks += 1;
+        if skipped_height.is_some() {
+            continue;
+        }
+
+        let state = (
+            chamber.rock_index,
+            chamber.jet_index,
+            chamber.get_surface_profile(),
+        );
+        let height = chamber.get_height() as u64;
+        if let Some((cycle_start_rocks, cycle_start_height)) =
+            seen_states.insert(state, (dropped_rocks, height))
+        {
+            let cycle_length = dropped_rocks - cycle_start_rocks;
+            let cycle_count = (rock_count - dropped_rocks) / cycle_length;
+            dropped_rocks += cycle_count * cycle_length;
+            skipped_height = Some(cycle_count * (height - cycle_start_height));
+        }
+    }
+    chamber.get_height() as u64 + skipped_height.unwrap_or(0)
+}
+
+pub struct PyroclasticFlow;
+
+impl Solution for PyroclasticFlow {
+    type Input = Vec<Jet>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(jets: &Self::Input) -> SolveResult<Answer> {
+        Ok(get_tower_height(jets, ROCK_COUNT).into())
+    }
+
+    fn part2(jets: &Self::Input) -> SolveResult<Answer> {
+        Ok(get_tower_height(jets, LARGE_ROCK_COUNT).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn test_input_parsing() {
+        assert_eq!(
+            parse_input("<>>\n").unwrap(),
+            vec![Jet::Left, Jet::Right, Jet::Right]
+        );
+        assert_eq!(parse_input(EXAMPLE_INPUT).unwrap().len(), 40);
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("<<x>").unwrap_err();
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 3);
+
+        let error = parse_input("").unwrap_err();
+        assert_eq!(error.expected, "a jet pattern of `<` and `>`");
+
+        let error = parse_input("<>\n<>").unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn test_jet_push() {
+        assert_eq!(Jet::Left.push(&[0b0011110]), Some(vec![0b0111100]));
+        assert_eq!(Jet::Right.push(&[0b0011110]), Some(vec![0b0001111]));
+        assert_eq!(Jet::Right.push(&[0b0001111]), None);
+        assert_eq!(Jet::Left.push(&[0b0001000, 0b1000000]), None);
+    }
+
+    #[test]
+    fn test_rock_rendering() {
+        let jets = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(
+            simulate_rocks(&jets, 1).render_top_rows(5),
+            "|..####.|\n+-------+\n"
+        );
+        assert_eq!(
+            simulate_rocks(&jets, 2).render_top_rows(5),
+            "|...#...|\n|..###..|\n|...#...|\n|..####.|\n+-------+\n"
+        );
+        assert_eq!(
+            simulate_rocks(&jets, 10).render_top_rows(4),
+            "|....#..|\n|....#..|\n|....##.|\n|##..##.|\n"
+        );
+    }
+
+    #[test]
+    fn test_surface_profile() {
+        let jets = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(Chamber::default().get_surface_profile(), [0; 7]);
+        assert_eq!(
+            simulate_rocks(&jets, 2).get_surface_profile(),
+            [4, 4, 1, 0, 1, 3, 4]
+        );
+        // Only pushed to the right, no rock ever reaches the leftmost column
+        let jets = parse_input(">").unwrap();
+        assert_eq!(
+            simulate_rocks(&jets, 100).get_surface_profile()[0],
+            MAX_SURFACE_DEPTH
+        );
+    }
+
+    #[test]
+    fn test_tower_height() {
+        let jets = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(get_tower_height(&jets, 0), 0);
+        assert_eq!(get_tower_height(&jets, 10), 17);
+        assert_eq!(
+            get_tower_height(&jets, 2022),
+            simulate_rocks(&jets, 2022).get_height() as u64
+        );
+        assert_eq!(get_tower_height(&jets, 2022), 3068);
+        assert_eq!(get_tower_height(&jets, 1000000000000), 1514285714288);
+    }
+
+    #[test]
+    fn test_cycle_detection_matches_simulation() {
+        let jets = parse_input("<<>").unwrap();
+        for rock_count in [1, 7, 100, 1234] {
+            assert_eq!(
+                get_tower_height(&jets, rock_count),
+                simulate_rocks(&jets, rock_count as usize).get_height() as u64
+            );
+        }
+    }
+
+    #[test]
+    fn test_single_jet_pattern() {
+        // With a single jet every round of the five rocks adds the same height
+        let jets = parse_input(">").unwrap();
+        let round_height =
+            simulate_rocks(&jets, 10).get_height() - simulate_rocks(&jets, 5).get_height();
+        let expected_height = (LARGE_ROCK_COUNT / 5) * round_height as u64;
+        assert_eq!(simulate_rocks(&jets, 5).get_height(), round_height);
+        assert_eq!(
+            PyroclasticFlow::solve_part_2(">"),
+            Ok(Answer::Integer(expected_height as i64))
+        );
+    }
+}
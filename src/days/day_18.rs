@@ -0,0 +1,159 @@
+use crate::error::{Result, SolveResult};
+use crate::geometry::{get_bounding_box, Point3};
+use crate::parsing::{normalize_input, parse_token, split_token};
+use crate::solution::{Answer, Solution};
+use std::collections::{HashSet, VecDeque};
+
+const DAY: u8 = 18;
+
+pub fn parse_cube_line(line: &str) -> Result<Point3<i32>> {
+    let (x, rest) = split_token(DAY, line, line.trim(), ",")?;
+    let (y, z) = split_token(DAY, line, rest, ",")?;
+    Ok(Point3::new(
+        parse_token(DAY, line, x, "an integer coordinate")?,
+        parse_token(DAY, line, y, "an integer coordinate")?,
+        parse_token(DAY, line, z, "an integer coordinate")?,
+    ))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Point3<i32>>> {
+    let input = &normalize_input(input);
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_cube_line(line).map_err(|e| e.shift_lines(i)))
+        .collect()
+}
+
+pub fn count_exposed_faces(cubes: &[Point3<i32>]) -> usize {
+    let droplet = cubes.iter().collect::<HashSet<&Point3<i32>>>();
+    droplet
+        .iter()
+        .flat_map(|cube| cube.get_face_neighbours())
+        .filter(|neighbour| !droplet.contains(neighbour))
+        .count()
+}
+
+// Flood fills the air around the droplet inside a bounding box with a layer of air on every side,
+// so only faces reachable from outside are counted and air pockets inside the droplet are not
+pub fn count_exterior_faces(cubes: &[Point3<i32>]) -> usize {
+    let droplet = cubes.iter().collect::<HashSet<&Point3<i32>>>();
+    let Some((min, max)) = get_bounding_box(cubes) else {
+        return 0;
+    };
+    let min = min - Point3::new(1, 1, 1);
+    let max = max + Point3::new(1, 1, 1);
+
+    let mut exterior_faces = 0;
+    let mut outside_air = HashSet::from([min]);
+    let mut search_queue = VecDeque::from([min]);
+    while let Some(air) = search_queue.pop_front() {
+        for neighbour in air.get_face_neighbours_within(min, max) {
+            if droplet.contains(&neighbour) {
+                exterior_faces += 1;
+            } else if outside_air.insert(neighbour) {
+                search_queue.push_back(neighbour);
+            }
+        }
+    }
+    exterior_faces
+}
+
+pub struct BoilingBoulders;
+
+impl Solution for BoilingBoulders {
+    type Input = Vec<Point3<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(cubes: &Self::Input) -> SolveResult<Answer> {
+        Ok(count_exposed_faces(cubes).into())
+    }
+
+    fn part2(cubes: &Self::Input) -> SolveResult<Answer> {
+        Ok(count_exterior_faces(cubes).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "2,2,2
+        1,2,2
+        3,2,2
+        2,1,2
+        2,3,2
+        2,2,1
+        2,2,3
+        2,2,4
+        2,2,6
+        1,2,5
+        3,2,5
+        2,1,5
+        2,3,5";
+
+    #[test]
+    fn test_input_parsing() {
+        assert_eq!(
+            parse_input("1,1,1\n-2,10,3").unwrap(),
+            vec![Point3::new(1, 1, 1), Point3::new(-2, 10, 3)]
+        );
+        assert_eq!(parse_input(EXAMPLE_INPUT).unwrap().len(), 13);
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("1,1,1\n1,x,1").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 3);
+        assert_eq!(error.expected, "an integer coordinate");
+
+        let error = parse_input("1,1").unwrap_err();
+        assert_eq!(error.expected, "a text containing `,`");
+
+        let error = parse_input("1,1,1,1").unwrap_err();
+        assert_eq!(error.column, 5);
+    }
+
+    #[test]
+    fn test_exposed_faces() {
+        assert_eq!(count_exposed_faces(&[]), 0);
+        assert_eq!(
+            count_exposed_faces(&[Point3::new(1, 1, 1), Point3::new(2, 1, 1)]),
+            10
+        );
+        assert_eq!(
+            count_exposed_faces(&parse_input(EXAMPLE_INPUT).unwrap()),
+            64
+        );
+    }
+
+    #[test]
+    fn test_exterior_faces() {
+        assert_eq!(count_exterior_faces(&[]), 0);
+        assert_eq!(count_exterior_faces(&[Point3::new(0, 0, 0)]), 6);
+        assert_eq!(
+            count_exterior_faces(&parse_input(EXAMPLE_INPUT).unwrap()),
+            58
+        );
+    }
+
+    #[test]
+    fn test_hollow_cube() {
+        let mut cubes = Vec::new();
+        for x in 0..3 {
+            for y in 0..3 {
+                for z in 0..3 {
+                    if (x, y, z) != (1, 1, 1) {
+                        cubes.push(Point3::new(x, y, z));
+                    }
+                }
+            }
+        }
+        assert_eq!(count_exposed_faces(&cubes), 54 + 6);
+        assert_eq!(count_exterior_faces(&cubes), 54);
+    }
+}
@@ -0,0 +1,386 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::parsing::{end_of_line, normalize_input, parse_token, split_token};
+use crate::solution::{Answer, Solution};
+use std::thread;
+
+const DAY: u8 = 19;
+const MINUTES: u32 = 24;
+const EXTENDED_MINUTES: u32 = 32;
+const EXTENDED_BLUEPRINT_COUNT: usize = 3;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Resource {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl Resource {
+    pub const ALL: [Resource; 4] = [
+        Resource::Ore,
+        Resource::Clay,
+        Resource::Obsidian,
+        Resource::Geode,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Resource::Ore => "ore",
+            Resource::Clay => "clay",
+            Resource::Obsidian => "obsidian",
+            Resource::Geode => "geode",
+        }
+    }
+}
+
+// Amounts of resources or robots, indexed by `Resource as usize`
+pub type Resources = [u32; 4];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Blueprint {
+    pub id: u32,
+    pub robot_costs: [Resources; 4],
+}
+
+impl Blueprint {
+    pub fn get_robot_cost(&self, robot: Resource, resource: Resource) -> u32 {
+        self.robot_costs[robot as usize][resource as usize]
+    }
+
+    // Only one robot is built per minute, so more robots than the most expensive cost of a resource
+    // can't produce anything that could be spent
+    pub fn get_useful_robot_counts(&self) -> Resources {
+        let mut robot_counts = [0, 0, 0, u32::MAX];
+        for costs in &self.robot_costs {
+            for resource in [Resource::Ore, Resource::Clay, Resource::Obsidian] {
+                let robot_count = &mut robot_counts[resource as usize];
+                *robot_count = (*robot_count).max(costs[resource as usize]);
+            }
+        }
+        robot_counts
+    }
+}
+
+fn parse_cost(line: &str, cost: &str) -> Result<(Resource, u32)> {
+    let (amount, resource_name) = split_token(DAY, line, cost, " ")?;
+    let amount = parse_token(DAY, line, amount, "a resource amount")?;
+    match Resource::ALL
+        .iter()
+        .find(|resource| resource.get_name() == resource_name)
+    {
+        Some(resource) => Ok((*resource, amount)),
+        None => Err(ParseError::new(
+            DAY,
+            line,
+            resource_name,
+            "`ore`, `clay`, `obsidian` or `geode`",
+        )),
+    }
+}
+
+pub fn parse_blueprint_line(line: &str) -> Result<Blueprint> {
+    let blueprint_line = line.trim();
+    let Some(blueprint_description) = blueprint_line.strip_prefix("Blueprint ") else {
+        return Err(ParseError::new(
+            DAY,
+            line,
+            blueprint_line,
+            "a line starting with `Blueprint `",
+        ));
+    };
+    let (id, mut robot_descriptions) = split_token(DAY, line, blueprint_description, ": ")?;
+    let id = parse_token(DAY, line, id, "a blueprint number")?;
+
+    let mut robot_costs = [[0; 4]; 4];
+    for robot in Resource::ALL {
+        let prefix = format!("Each {} robot costs ", robot.get_name());
+        let Some(robot_description) = robot_descriptions.trim_start().strip_prefix(&prefix) else {
+            let expected = format!("a text starting with `{}`", prefix);
+            return Err(ParseError::new(
+                DAY,
+                line,
+                robot_descriptions.trim_start(),
+                &expected,
+            ));
+        };
+        let (costs, rest) = split_token(DAY, line, robot_description, ".")?;
+        for cost in costs.split(" and ") {
+            let (resource, amount) = parse_cost(line, cost)?;
+            robot_costs[robot as usize][resource as usize] = amount;
+        }
+        robot_descriptions = rest;
+    }
+    if !robot_descriptions.is_empty() {
+        return Err(ParseError::new(
+            DAY,
+            line,
+            robot_descriptions,
+            "the end of the line",
+        ));
+    }
+
+    Ok(Blueprint { id, robot_costs })
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Blueprint>> {
+    let input = &normalize_input(input);
+    let blueprints = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_blueprint_line(line).map_err(|e| e.shift_lines(i)))
+        .collect::<Result<Vec<Blueprint>>>()?;
+    if blueprints.is_empty() {
+        return Err(ParseError::new(
+            DAY,
+            input,
+            end_of_line(input),
+            "at least one blueprint",
+        ));
+    }
+    Ok(blueprints)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pruning {
+    // Skips robots for resources that are already produced as fast as they can be spent
+    pub limit_robot_counts: bool,
+    // Abandons branches that can't beat the best result even with a new geode robot every minute
+    pub bound_geodes: bool,
+}
+
+impl Default for Pruning {
+    fn default() -> Self {
+        Self {
+            limit_robot_counts: true,
+            bound_geodes: true,
+        }
+    }
+}
+
+impl Pruning {
+    pub fn none() -> Self {
+        Self {
+            limit_robot_counts: false,
+            bound_geodes: false,
+        }
+    }
+}
+
+struct GeodeSearch<'a> {
+    blueprint: &'a Blueprint,
+    pruning: Pruning,
+    useful_robot_counts: Resources,
+    max_geodes: u32,
+}
+
+impl GeodeSearch<'_> {
+    // Instead of branching every minute, every branch decides which robot to build next and waits
+    // until it can be afforded
+    fn search(&mut self, time_left: u32, robots: Resources, resources: Resources) {
+        let geode = Resource::Geode as usize;
+        let geodes = resources[geode] + robots[geode] * time_left;
+        self.max_geodes = self.max_geodes.max(geodes);
+        if self.pruning.bound_geodes
+            && geodes + time_left * time_left.saturating_sub(1) / 2 <= self.max_geodes
+        {
+            return;
+        }
+
+        for robot in Resource::ALL.iter().rev().map(|robot| *robot as usize) {
+            if self.pruning.limit_robot_counts && robots[robot] >= self.useful_robot_counts[robot] {
+                continue;
+            }
+            let costs = &self.blueprint.robot_costs[robot];
+            let Some(waiting_time) = (0..4)
+                .filter(|resource| costs[*resource] > resources[*resource])
+                .map(|resource| {
+                    let missing = costs[resource] - resources[resource];
+                    (robots[resource] > 0).then(|| missing.div_ceil(robots[resource]))
+                })
+                .try_fold(0, |waiting_time, resource_waiting_time| {
+                    resource_waiting_time.map(|time| time.max(waiting_time))
+                })
+            else {
+                continue;
+            };
+            // The new robot has to be done with at least a minute left to collect anything
+            if waiting_time + 1 >= time_left {
+                continue;
+            }
+
+            let elapsed_time = waiting_time + 1;
+            let mut new_robots = robots;
+            new_robots[robot] += 1;
+            let mut new_resources = resources;
+            for resource in 0..4 {
+                new_resources[resource] += robots[resource] * elapsed_time;
+                new_resources[resource] -= costs[resource];
+            }
+            self.search(time_left - elapsed_time, new_robots, new_resources);
+        }
+    }
+}
+
+pub fn get_max_geodes(blueprint: &Blueprint, minutes: u32, pruning: Pruning) -> u32 {
+    let mut geode_search = GeodeSearch {
+        blueprint,
+        pruning,
+        useful_robot_counts: blueprint.get_useful_robot_counts(),
+        max_geodes: 0,
+    };
+    geode_search.search(minutes, [1, 0, 0, 0], [0; 4]);
+    geode_search.max_geodes
+}
+
+// Every blueprint is searched on its own thread
+pub fn evaluate_blueprints(blueprints: &[Blueprint], minutes: u32, pruning: Pruning) -> Vec<u32> {
+    thread::scope(|scope| {
+        let searches = blueprints
+            .iter()
+            .map(|blueprint| scope.spawn(move || get_max_geodes(blueprint, minutes, pruning)))
+            .collect::<Vec<_>>();
+        searches
+            .into_iter()
+            .map(|search| search.join().unwrap())
+            .collect()
+    })
+}
+
+pub fn get_quality_level_sum(blueprints: &[Blueprint], minutes: u32, pruning: Pruning) -> u32 {
+    blueprints
+        .iter()
+        .zip(evaluate_blueprints(blueprints, minutes, pruning))
+        .map(|(blueprint, max_geodes)| blueprint.id * max_geodes)
+        .sum()
+}
+
+pub struct NotEnoughMinerals;
+
+impl Solution for NotEnoughMinerals {
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(blueprints: &Self::Input) -> SolveResult<Answer> {
+        Ok(get_quality_level_sum(blueprints, MINUTES, Pruning::default()).into())
+    }
+
+    fn part2(blueprints: &Self::Input) -> SolveResult<Answer> {
+        let first_blueprints = &blueprints[..blueprints.len().min(EXTENDED_BLUEPRINT_COUNT)];
+        Ok(
+            evaluate_blueprints(first_blueprints, EXTENDED_MINUTES, Pruning::default())
+                .into_iter()
+                .map(u64::from)
+                .product::<u64>()
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+        Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+    #[test]
+    fn test_input_parsing() {
+        let blueprints = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(
+            blueprints[0],
+            Blueprint {
+                id: 1,
+                robot_costs: [[4, 0, 0, 0], [2, 0, 0, 0], [3, 14, 0, 0], [2, 0, 7, 0]],
+            }
+        );
+        assert_eq!(blueprints[1].id, 2);
+        assert_eq!(
+            blueprints[1].get_robot_cost(Resource::Geode, Resource::Obsidian),
+            12
+        );
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input(
+            "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 mud. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.",
+        )
+        .unwrap_err();
+        assert_eq!(error.column, 66);
+        assert_eq!(error.expected, "`ore`, `clay`, `obsidian` or `geode`");
+
+        let error = parse_input(
+            "Blueprint 1: Each ore robot costs 4 ore. Each obsidian robot costs 3 ore and 14 clay.",
+        )
+        .unwrap_err();
+        assert_eq!(error.column, 42);
+        assert_eq!(
+            error.expected,
+            "a text starting with `Each clay robot costs `"
+        );
+
+        let error = parse_input("Blueprint x: Each ore robot costs 4 ore.").unwrap_err();
+        assert_eq!(error.column, 11);
+
+        let error = parse_input("").unwrap_err();
+        assert_eq!(error.expected, "at least one blueprint");
+    }
+
+    #[test]
+    fn test_useful_robot_counts() {
+        let blueprints = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(
+            blueprints[0].get_useful_robot_counts(),
+            [4, 14, 7, u32::MAX]
+        );
+    }
+
+    #[test]
+    fn test_max_geodes() {
+        let blueprints = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(get_max_geodes(&blueprints[0], 24, Pruning::default()), 9);
+        assert_eq!(get_max_geodes(&blueprints[1], 24, Pruning::default()), 12);
+        assert_eq!(get_max_geodes(&blueprints[0], 32, Pruning::default()), 56);
+        assert_eq!(get_max_geodes(&blueprints[1], 32, Pruning::default()), 62);
+        assert_eq!(get_max_geodes(&blueprints[0], 0, Pruning::default()), 0);
+    }
+
+    #[test]
+    fn test_pruning_does_not_change_results() {
+        let blueprints = parse_input(EXAMPLE_INPUT).unwrap();
+        let expected_geodes = evaluate_blueprints(&blueprints, 20, Pruning::default());
+        let pruning_variants = [
+            Pruning::none(),
+            Pruning {
+                limit_robot_counts: true,
+                bound_geodes: false,
+            },
+            Pruning {
+                limit_robot_counts: false,
+                bound_geodes: true,
+            },
+        ];
+        for pruning in pruning_variants {
+            assert_eq!(
+                evaluate_blueprints(&blueprints, 20, pruning),
+                expected_geodes
+            );
+        }
+    }
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(
+            NotEnoughMinerals::solve_part_1(EXAMPLE_INPUT).unwrap(),
+            Answer::Integer(33)
+        );
+        assert_eq!(
+            NotEnoughMinerals::solve_part_2(EXAMPLE_INPUT).unwrap(),
+            Answer::Integer(56 * 62)
+        );
+    }
+}
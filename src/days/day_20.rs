@@ -0,0 +1,234 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::parsing::{end_of_line, normalize_input, parse_token};
+use crate::solution::{Answer, Solution};
+
+const DAY: u8 = 20;
+const DECRYPTION_KEY: i64 = 811589153;
+const MIXING_ROUNDS: usize = 10;
+const GROVE_COORDINATE_OFFSETS: [usize; 3] = [1000, 2000, 3000];
+
+// Mixes a circular sequence by moving every value as many steps as the value itself, in the order
+// of the original sequence. The order is kept as indices into the original values, so duplicate
+// values stay distinguishable.
+#[derive(Clone, Debug)]
+pub struct CircularMixer {
+    values: Vec<i64>,
+    order: Vec<usize>,
+}
+
+impl CircularMixer {
+    pub fn new(values: Vec<i64>) -> Self {
+        Self {
+            order: (0..values.len()).collect(),
+            values,
+        }
+    }
+
+    pub fn mix(&mut self) {
+        // A value moving around the circle skips itself, so a full lap takes one step less than the
+        // length of the sequence
+        let lap_length = self.values.len() as i64 - 1;
+        if lap_length < 1 {
+            return;
+        }
+        for (index, value) in self.values.iter().enumerate() {
+            let position = self.order.iter().position(|i| *i == index).unwrap();
+            self.order.remove(position);
+            let new_position = (position as i64 + value).rem_euclid(lap_length);
+            self.order.insert(new_position as usize, index);
+        }
+    }
+
+    pub fn get_mixed_values(&self) -> Vec<i64> {
+        self.order.iter().map(|i| self.values[*i]).collect()
+    }
+
+    pub fn get_value_after(&self, value: i64, offset: usize) -> Option<i64> {
+        let mixed_values = self.get_mixed_values();
+        let position = mixed_values.iter().position(|v| *v == value)?;
+        Some(mixed_values[(position + offset) % mixed_values.len()])
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<i64>> {
+    let input = &normalize_input(input);
+    let values = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            parse_token(DAY, line, line.trim(), "an integer value").map_err(|e| e.shift_lines(i))
+        })
+        .collect::<Result<Vec<i64>>>()?;
+    if !values.contains(&0) {
+        let last_line = input.lines().last().unwrap_or(input);
+        return Err(
+            ParseError::new(DAY, last_line, end_of_line(last_line), "a value of 0")
+                .shift_lines(values.len().saturating_sub(1)),
+        );
+    }
+    Ok(values)
+}
+
+pub fn get_grove_coordinates_sum(mixer: &CircularMixer) -> i64 {
+    GROVE_COORDINATE_OFFSETS
+        .iter()
+        .map(|offset| mixer.get_value_after(0, *offset).unwrap())
+        .sum()
+}
+
+pub fn decrypt(values: &[i64], decryption_key: i64, rounds: usize) -> CircularMixer {
+    let mut mixer = CircularMixer::new(values.iter().map(|value| value * decryption_key).collect());
+    for _ in 0..rounds {
+        mixer.mix();
+    }
+    mixer
+}
+
+pub struct GrovePositioningSystem;
+
+impl Solution for GrovePositioningSystem {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(values: &Self::Input) -> SolveResult<Answer> {
+        Ok(get_grove_coordinates_sum(&decrypt(values, 1, 1)).into())
+    }
+
+    fn part2(values: &Self::Input) -> SolveResult<Answer> {
+        Ok(get_grove_coordinates_sum(&decrypt(values, DECRYPTION_KEY, MIXING_ROUNDS)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "1\n2\n-3\n3\n-2\n0\n4";
+
+    // The mixed sequence is circular, so it is compared starting from its first value
+    fn rotate_to_first_value(values: &[i64], mut mixed_values: Vec<i64>) -> Vec<i64> {
+        if let Some(position) = mixed_values.iter().position(|v| Some(v) == values.first()) {
+            mixed_values.rotate_left(position);
+        }
+        mixed_values
+    }
+
+    fn mix_values(values: &[i64], rounds: usize) -> Vec<i64> {
+        let mut mixer = CircularMixer::new(values.to_vec());
+        for _ in 0..rounds {
+            mixer.mix();
+        }
+        rotate_to_first_value(values, mixer.get_mixed_values())
+    }
+
+    // Moves every value one step at a time by swapping it with its neighbour, as in the puzzle
+    fn mix_values_by_swapping(values: &[i64]) -> Vec<i64> {
+        let length = values.len();
+        let mut order = (0..length).collect::<Vec<usize>>();
+        for (index, value) in values.iter().enumerate() {
+            let mut position = order.iter().position(|i| *i == index).unwrap();
+            for _ in 0..value.unsigned_abs() {
+                let next_position = if *value > 0 {
+                    (position + 1) % length
+                } else {
+                    (position + length - 1) % length
+                };
+                order.swap(position, next_position);
+                position = next_position;
+            }
+        }
+        rotate_to_first_value(values, order.iter().map(|i| values[*i]).collect())
+    }
+
+    #[test]
+    fn test_input_parsing() {
+        assert_eq!(
+            parse_input(EXAMPLE_INPUT).unwrap(),
+            vec![1, 2, -3, 3, -2, 0, 4]
+        );
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("1\nx\n0").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.expected, "an integer value");
+
+        let error = parse_input("1\n2").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 2);
+        assert_eq!(error.expected, "a value of 0");
+    }
+
+    #[test]
+    fn test_example_mixing() {
+        let values = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(mix_values(&values, 1), vec![1, 2, -3, 4, 0, 3, -2]);
+    }
+
+    #[test]
+    fn test_mixing_wraps_around() {
+        assert_eq!(mix_values(&[0, 4, 1], 1), vec![0, 1, 4]);
+        assert_eq!(mix_values(&[4, 0, 1], 1), vec![4, 1, 0]);
+        // Moving forward past the end, backward past the start and by exactly one or more laps
+        for values in [
+            vec![0, -2, 5, 1],
+            vec![0, 3, 1, 2],
+            vec![0, 6, 1, 2],
+            vec![-3, 1, 0, 2],
+            vec![1, 2, 3, 0, -1, -2, -3],
+        ] {
+            assert_eq!(mix_values(&values, 1), mix_values_by_swapping(&values));
+        }
+    }
+
+    #[test]
+    fn test_mixing_large_distances() {
+        for values in [
+            vec![0, 1, 4002, 3, 4],
+            vec![0, 1, -3998, 3, 4],
+            vec![0, -23, 2, 3, 11],
+            vec![9, 0, -9, 8, -8, 4],
+            vec![0, 5, 10, -15, 20],
+        ] {
+            assert_eq!(mix_values(&values, 1), mix_values_by_swapping(&values));
+        }
+        // Every lap around the four other values takes 4 steps
+        let mixed_values = mix_values(&[0, 1, 4002, 3, 4], 1);
+        let position = mixed_values.iter().position(|v| *v == 4002).unwrap();
+        assert_eq!(
+            mix_values(&[0, 1, 2, 3, 4], 1).iter().position(|v| *v == 2),
+            Some(position)
+        );
+    }
+
+    #[test]
+    fn test_mixing_duplicates() {
+        assert_eq!(mix_values(&[0, 1, 1, 1], 1), vec![0, 1, 1, 1]);
+        for values in [vec![0, 2, 2, -1], vec![3, 0, 3, -2, 3, -2]] {
+            assert_eq!(mix_values(&values, 1), mix_values_by_swapping(&values));
+        }
+    }
+
+    #[test]
+    fn test_mixing_short_sequences() {
+        assert_eq!(mix_values(&[], 1), vec![]);
+        assert_eq!(mix_values(&[5], 3), vec![5]);
+        assert_eq!(mix_values(&[0, 7], 1), vec![0, 7]);
+    }
+
+    #[test]
+    fn test_grove_coordinates() {
+        let values = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(get_grove_coordinates_sum(&decrypt(&values, 1, 1)), 3);
+        let mixer = decrypt(&values, DECRYPTION_KEY, 1);
+        assert_eq!(mixer.get_value_after(0, 1), Some(-2434767459));
+        assert_eq!(
+            get_grove_coordinates_sum(&decrypt(&values, DECRYPTION_KEY, MIXING_ROUNDS)),
+            1623178306
+        );
+    }
+}
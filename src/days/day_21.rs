@@ -0,0 +1,420 @@
+use crate::error::{ParseError, Result, SolveError, SolveResult};
+use crate::parsing::{end_of_line, normalize_input, parse_token, split_token};
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+const DAY: u8 = 21;
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operation {
+    // Monkeys only yell integers, so a division has to be exact
+    pub fn apply(&self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Operation::Add => a.checked_add(b),
+            Operation::Subtract => a.checked_sub(b),
+            Operation::Multiply => a.checked_mul(b),
+            Operation::Divide if b != 0 && a % b == 0 => a.checked_div(b),
+            Operation::Divide => None,
+        }
+    }
+
+    // Returns the left operand a for which `a op b == result`
+    pub fn solve_left_operand(&self, result: i64, b: i64) -> Option<i64> {
+        match self {
+            Operation::Add => Operation::Subtract.apply(result, b),
+            Operation::Subtract => Operation::Add.apply(result, b),
+            Operation::Multiply => Operation::Divide.apply(result, b),
+            Operation::Divide => Operation::Multiply.apply(result, b),
+        }
+    }
+
+    // Returns the right operand b for which `a op b == result`
+    pub fn solve_right_operand(&self, a: i64, result: i64) -> Option<i64> {
+        match self {
+            Operation::Add => Operation::Subtract.apply(result, a),
+            Operation::Subtract => Operation::Subtract.apply(a, result),
+            Operation::Multiply => Operation::Divide.apply(result, a),
+            Operation::Divide => Operation::Divide.apply(a, result),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expression {
+    Number(i64),
+    Operation(String, Operation, String),
+}
+
+// Every monkey is a node whose operands are the monkeys it listens to
+#[derive(Clone, Debug, Default)]
+pub struct ExpressionGraph {
+    pub expressions: HashMap<String, Expression>,
+}
+
+impl ExpressionGraph {
+    fn evaluate_with_cache<'a>(
+        &'a self,
+        name: &'a str,
+        cache: &mut HashMap<&'a str, Option<i64>>,
+    ) -> Option<i64> {
+        if let Some(value) = cache.get(name) {
+            return *value;
+        }
+        let value = match self.expressions.get(name)? {
+            Expression::Number(value) => Some(*value),
+            Expression::Operation(a, operation, b) => {
+                let a = self.evaluate_with_cache(a, cache);
+                let b = self.evaluate_with_cache(b, cache);
+                a.zip(b).and_then(|(a, b)| operation.apply(a, b))
+            }
+        };
+        cache.insert(name, value);
+        value
+    }
+
+    // Returns None if the result isn't an integer or doesn't fit into an i64
+    pub fn evaluate(&self, name: &str) -> Option<i64> {
+        self.evaluate_with_cache(name, &mut HashMap::new())
+    }
+
+    pub fn depends_on(&self, name: &str, dependency: &str) -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = vec![name];
+        while let Some(current_name) = stack.pop() {
+            if current_name == dependency {
+                return true;
+            }
+            if let Some(Expression::Operation(a, _, b)) = self.expressions.get(current_name) {
+                for operand in [a, b] {
+                    if visited.insert(operand.as_str()) {
+                        stack.push(operand);
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    // Walks from `name` down to the unknown monkey and inverts every operation on the way, which
+    // requires the unknown monkey to appear in only one operand of every operation
+    pub fn solve_for(&self, name: &str, result: i64, unknown: &str) -> Option<i64> {
+        let mut current_name = name;
+        let mut result = result;
+        while current_name != unknown {
+            let Some(Expression::Operation(a, operation, b)) = self.expressions.get(current_name)
+            else {
+                return None;
+            };
+            match (self.depends_on(a, unknown), self.depends_on(b, unknown)) {
+                (true, false) => {
+                    result = operation.solve_left_operand(result, self.evaluate(b)?)?;
+                    current_name = a;
+                }
+                (false, true) => {
+                    result = operation.solve_right_operand(self.evaluate(a)?, result)?;
+                    current_name = b;
+                }
+                _ => return None,
+            }
+        }
+        Some(result)
+    }
+
+    // The root monkey compares both of its operands for equality instead of applying its operation
+    pub fn solve_equality_for(&self, name: &str, unknown: &str) -> Option<i64> {
+        let Some(Expression::Operation(a, _, b)) = self.expressions.get(name) else {
+            return None;
+        };
+        match (self.depends_on(a, unknown), self.depends_on(b, unknown)) {
+            (true, false) => self.solve_for(a, self.evaluate(b)?, unknown),
+            (false, true) => self.solve_for(b, self.evaluate(a)?, unknown),
+            _ => None,
+        }
+    }
+}
+
+fn parse_monkey_name<'a>(line: &str, name: &'a str) -> Result<&'a str> {
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase()) {
+        Ok(name)
+    } else {
+        Err(ParseError::new(
+            DAY,
+            line,
+            name,
+            "a monkey name of lowercase letters",
+        ))
+    }
+}
+
+pub fn parse_monkey_line(line: &str) -> Result<(String, Expression)> {
+    let (name, job) = split_token(DAY, line, line.trim(), ": ")?;
+    let name = String::from(parse_monkey_name(line, name)?);
+    let tokens = job.split(' ').collect::<Vec<&str>>();
+    let expression = match tokens[..] {
+        [value] => Expression::Number(parse_token(DAY, line, value, "an integer or an operation")?),
+        [a, operator, b] => {
+            let operation = match operator {
+                "+" => Operation::Add,
+                "-" => Operation::Subtract,
+                "*" => Operation::Multiply,
+                "/" => Operation::Divide,
+                _ => return Err(ParseError::new(DAY, line, operator, "`+`, `-`, `*` or `/`")),
+            };
+            Expression::Operation(
+                String::from(parse_monkey_name(line, a)?),
+                operation,
+                String::from(parse_monkey_name(line, b)?),
+            )
+        }
+        _ => {
+            return Err(ParseError::new(
+                DAY,
+                line,
+                job,
+                "an integer or an operation like `abcd + efgh`",
+            ))
+        }
+    };
+    Ok((name, expression))
+}
+
+pub fn parse_input(input: &str) -> Result<ExpressionGraph> {
+    let input = &normalize_input(input);
+    let lines = input.lines().collect::<Vec<&str>>();
+    let mut graph = ExpressionGraph::default();
+    for (i, line) in lines.iter().enumerate() {
+        let (name, expression) = parse_monkey_line(line).map_err(|e| e.shift_lines(i))?;
+        if graph.expressions.contains_key(&name) {
+            let name = &line[line.find(&name).unwrap()..][..name.len()];
+            return Err(ParseError::new(DAY, line, name, "a new monkey name").shift_lines(i));
+        }
+        graph.expressions.insert(name, expression);
+    }
+
+    // The operands are the second and the last token of an operation line
+    for (i, line) in lines.iter().enumerate() {
+        let tokens = line.split_whitespace().collect::<Vec<&str>>();
+        let name = tokens[0].trim_end_matches(':');
+        let Some(Expression::Operation(a, _, b)) = graph.expressions.get(name) else {
+            continue;
+        };
+        if let Some(operand) =
+            [(a, tokens[1]), (b, tokens[3])]
+                .into_iter()
+                .find_map(|(operand, token)| {
+                    (!graph.expressions.contains_key(operand)).then_some(token)
+                })
+        {
+            return Err(ParseError::new(DAY, line, operand, "an existing monkey").shift_lines(i));
+        }
+        if graph.depends_on(a, name) || graph.depends_on(b, name) {
+            return Err(ParseError::new(
+                DAY,
+                line,
+                name,
+                "a monkey that doesn't depend on its own number",
+            )
+            .shift_lines(i));
+        }
+    }
+
+    if !graph.expressions.contains_key(ROOT) {
+        let last_line = lines.last().copied().unwrap_or(input);
+        return Err(ParseError::new(
+            DAY,
+            last_line,
+            end_of_line(last_line),
+            &format!("a monkey named `{}`", ROOT),
+        )
+        .shift_lines(lines.len().saturating_sub(1)));
+    }
+
+    Ok(graph)
+}
+
+pub struct MonkeyMath;
+
+impl Solution for MonkeyMath {
+    type Input = ExpressionGraph;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(graph: &Self::Input) -> SolveResult<Answer> {
+        match graph.evaluate(ROOT) {
+            Some(value) => Ok(value.into()),
+            None => Err(SolveError::no_answer(
+                DAY,
+                1,
+                "the root monkey doesn't yell an integer",
+            )),
+        }
+    }
+
+    fn part2(graph: &Self::Input) -> SolveResult<Answer> {
+        match graph.solve_equality_for(ROOT, HUMAN) {
+            Some(value) => Ok(value.into()),
+            None => Err(SolveError::no_answer(
+                DAY,
+                2,
+                "no integer to yell passes the root monkey's equality test",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "root: pppw + sjmn
+        dbpl: 5
+        cczh: sllz + lgvd
+        zczc: 2
+        ptdq: humn - dvpt
+        dvpt: 3
+        lfqf: 4
+        humn: 5
+        ljgn: 2
+        sjmn: drzm * dbpl
+        sllz: 4
+        pppw: cczh / lfqf
+        lgvd: ljgn * ptdq
+        drzm: hmdt - zczc
+        hmdt: 32";
+
+    #[test]
+    fn test_monkey_line_parsing() {
+        assert_eq!(
+            parse_monkey_line("dbpl: 5").unwrap(),
+            (String::from("dbpl"), Expression::Number(5))
+        );
+        assert_eq!(
+            parse_monkey_line("root: pppw + sjmn").unwrap(),
+            (
+                String::from("root"),
+                Expression::Operation(String::from("pppw"), Operation::Add, String::from("sjmn"))
+            )
+        );
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("root: a % b\na: 1\nb: 2").unwrap_err();
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 9);
+
+        let error = parse_input("root: a + b\na: x").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 4);
+
+        let error = parse_input("root: a + b\na: 1").unwrap_err();
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 11);
+        assert_eq!(error.expected, "an existing monkey");
+
+        let error = parse_input("root: a + b\na: b * b\nb: a - b").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 1);
+        assert_eq!(
+            error.expected,
+            "a monkey that doesn't depend on its own number"
+        );
+
+        let error = parse_input("a: 1\na: 2").unwrap_err();
+        assert_eq!(error.line, 2);
+
+        let error = parse_input("a: 1\nb: 2").unwrap_err();
+        assert_eq!(error.expected, "a monkey named `root`");
+    }
+
+    #[test]
+    fn test_operations() {
+        assert_eq!(Operation::Divide.apply(7, 2), None);
+        assert_eq!(Operation::Divide.apply(7, 0), None);
+        assert_eq!(Operation::Multiply.apply(i64::MAX, 2), None);
+        for operation in [
+            Operation::Add,
+            Operation::Subtract,
+            Operation::Multiply,
+            Operation::Divide,
+        ] {
+            let result = operation.apply(12, 4).unwrap();
+            assert_eq!(operation.solve_left_operand(result, 4), Some(12));
+            assert_eq!(operation.solve_right_operand(12, result), Some(4));
+        }
+    }
+
+    #[test]
+    fn test_evaluation() {
+        let graph = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(graph.evaluate("root"), Some(152));
+        assert_eq!(graph.evaluate("sjmn"), Some(150));
+        assert_eq!(graph.evaluate("unknown"), None);
+    }
+
+    #[test]
+    fn test_shared_operands() {
+        let graph = parse_input("root: a + a\na: b * b\nb: humn - c\nc: 1\nhumn: 3").unwrap();
+        assert_eq!(graph.evaluate("root"), Some(8));
+        assert!(graph.depends_on("root", "humn"));
+        assert!(!graph.depends_on("c", "humn"));
+        assert_eq!(graph.solve_equality_for("root", "humn"), None);
+    }
+
+    #[test]
+    fn test_solving_for_human() {
+        let graph = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(graph.solve_equality_for("root", "humn"), Some(301));
+        assert_eq!(graph.solve_for("sjmn", 150, "hmdt"), Some(32));
+        assert_eq!(graph.solve_for("sjmn", 151, "hmdt"), None);
+
+        // The unknown monkey on the right of a subtraction and a division
+        let graph =
+            parse_input("root: a + b\na: c / d\nb: 2\nc: 12\nd: e - humn\ne: 5\nhumn: 0").unwrap();
+        assert_eq!(graph.solve_equality_for("root", "humn"), Some(-1));
+    }
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(
+            MonkeyMath::solve_part_1(EXAMPLE_INPUT).unwrap(),
+            Answer::Integer(152)
+        );
+        assert_eq!(
+            MonkeyMath::solve_part_2(EXAMPLE_INPUT).unwrap(),
+            Answer::Integer(301)
+        );
+    }
+    #[test]
+    fn test_solution_without_answer() {
+        // Division by zero leaves the root monkey without an integer to yell
+        let input = "root: a / b\na: 1\nb: humn - c\nc: 0\nhumn: 0";
+        assert!(matches!(
+            MonkeyMath::solve_part_1(input),
+            Err(SolveError::NoAnswer {
+                day: 21,
+                part: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            MonkeyMath::solve_part_2("root: a + a\na: b * b\nb: humn - c\nc: 1\nhumn: 3"),
+            Err(SolveError::NoAnswer {
+                day: 21,
+                part: 2,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,650 @@
+use crate::error::{ParseError, Result, SolveError, SolveResult};
+use crate::geometry::Point3;
+use crate::parsing::{end_of_line, normalize_input, parse_token, split_blocks};
+use crate::solution::{Answer, Solution};
+use std::collections::VecDeque;
+
+const DAY: u8 = 22;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Move(usize),
+    TurnLeft,
+    TurnRight,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Facing {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Facing {
+    pub const ALL: [Facing; 4] = [Facing::Right, Facing::Down, Facing::Left, Facing::Up];
+
+    pub fn get_value(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(self.get_value() + 1) % 4]
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(self.get_value() + 3) % 4]
+    }
+
+    pub fn reverse(&self) -> Self {
+        Self::ALL[(self.get_value() + 2) % 4]
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Board {
+    pub tiles: Vec<Vec<Tile>>,
+    pub width: usize,
+}
+
+impl Board {
+    pub fn get_height(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn get_tile(&self, (x, y): (usize, usize)) -> Tile {
+        self.tiles
+            .get(y)
+            .and_then(|row| row.get(x))
+            .copied()
+            .unwrap_or(Tile::Void)
+    }
+
+    // The neighbouring tile on the map, or None if the step leaves the map
+    pub fn get_neighbour(&self, (x, y): (usize, usize), facing: Facing) -> Option<(usize, usize)> {
+        let neighbour = match facing {
+            Facing::Right => (x + 1, y),
+            Facing::Down => (x, y + 1),
+            Facing::Left => (x.checked_sub(1)?, y),
+            Facing::Up => (x, y.checked_sub(1)?),
+        };
+        (self.get_tile(neighbour) != Tile::Void).then_some(neighbour)
+    }
+
+    pub fn get_start_position(&self) -> Option<(usize, usize)> {
+        let x = self
+            .tiles
+            .first()?
+            .iter()
+            .position(|tile| *tile == Tile::Open)?;
+        Some((x, 0))
+    }
+}
+
+pub trait Wrapping {
+    // Returns where the walker ends up when stepping off the map
+    fn wrap(
+        &self,
+        board: &Board,
+        position: (usize, usize),
+        facing: Facing,
+    ) -> ((usize, usize), Facing);
+}
+
+// Walking off the map continues on the opposite side of the same row or column
+pub struct FlatWrapping;
+
+impl Wrapping for FlatWrapping {
+    fn wrap(
+        &self,
+        board: &Board,
+        position: (usize, usize),
+        facing: Facing,
+    ) -> ((usize, usize), Facing) {
+        let mut position = position;
+        while let Some(neighbour) = board.get_neighbour(position, facing.reverse()) {
+            position = neighbour;
+        }
+        (position, facing)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CubeFace {
+    pub net_position: (usize, usize),
+    pub normal: Point3<i32>,
+    pub right: Point3<i32>,
+    pub down: Point3<i32>,
+}
+
+impl CubeFace {
+    pub fn get_direction(&self, facing: Facing) -> Point3<i32> {
+        match facing {
+            Facing::Right => self.right,
+            Facing::Down => self.down,
+            Facing::Left => -self.right,
+            Facing::Up => -self.down,
+        }
+    }
+
+    // Folding across an edge turns the face towards the direction of the edge, and walking
+    // across the edge continues downwards around the cube, away from the old normal
+    pub fn fold(&self, facing: Facing, net_position: (usize, usize)) -> Self {
+        let direction = self.get_direction(facing);
+        let (right, down) = match facing {
+            Facing::Right | Facing::Left => (self.normal * -direction.dot(self.right), self.down),
+            Facing::Down | Facing::Up => (self.right, self.normal * -direction.dot(self.down)),
+        };
+        Self {
+            net_position,
+            normal: direction,
+            right,
+            down,
+        }
+    }
+}
+
+// Every face of the net gets its orientation on a cube centered at the origin, which maps every edge
+// of the net to the face that it touches after folding, whatever the shape of the net is
+pub struct CubeWrapping {
+    pub face_size: usize,
+    pub faces: Vec<CubeFace>,
+}
+
+impl CubeWrapping {
+    // Returns None if the board isn't a net of six square faces that folds into a cube
+    pub fn new(board: &Board) -> Option<Self> {
+        let tile_count = board
+            .tiles
+            .iter()
+            .flatten()
+            .filter(|tile| **tile != Tile::Void)
+            .count();
+        let face_size = (1..)
+            .take_while(|size| 6 * size * size <= tile_count)
+            .last()
+            .filter(|size| 6 * size * size == tile_count)?;
+
+        let mut net_positions = Vec::new();
+        for net_y in 0..board.get_height().div_ceil(face_size) {
+            for net_x in 0..board.width.div_ceil(face_size) {
+                let corner = (net_x * face_size, net_y * face_size);
+                if board.get_tile(corner) != Tile::Void {
+                    net_positions.push((net_x, net_y));
+                }
+            }
+        }
+        if net_positions.len() != 6 {
+            return None;
+        }
+
+        let mut faces = vec![CubeFace {
+            net_position: net_positions[0],
+            normal: Point3::new(0, 0, 1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        }];
+        let mut search_queue = VecDeque::from([faces[0]]);
+        while let Some(face) = search_queue.pop_front() {
+            let (net_x, net_y) = face.net_position;
+            for facing in Facing::ALL {
+                let neighbour_position = match facing {
+                    Facing::Right => (net_x + 1, net_y),
+                    Facing::Down => (net_x, net_y + 1),
+                    Facing::Left if net_x > 0 => (net_x - 1, net_y),
+                    Facing::Up if net_y > 0 => (net_x, net_y - 1),
+                    _ => continue,
+                };
+                if net_positions.contains(&neighbour_position)
+                    && !faces.iter().any(|f| f.net_position == neighbour_position)
+                {
+                    let neighbour = face.fold(facing, neighbour_position);
+                    faces.push(neighbour);
+                    search_queue.push_back(neighbour);
+                }
+            }
+        }
+
+        // A valid net is connected, covers every side of the cube once and has no gaps in its faces
+        let covers_cube = faces.len() == 6
+            && faces
+                .iter()
+                .enumerate()
+                .all(|(i, face)| faces[..i].iter().all(|f| f.normal != face.normal));
+        let has_full_faces = faces.iter().all(|face| {
+            let (net_x, net_y) = face.net_position;
+            (0..face_size).all(|y| {
+                (0..face_size).all(|x| {
+                    let position = (net_x * face_size + x, net_y * face_size + y);
+                    board.get_tile(position) != Tile::Void
+                })
+            })
+        });
+        (covers_cube && has_full_faces).then_some(Self { face_size, faces })
+    }
+
+    fn get_face(&self, (x, y): (usize, usize)) -> Option<&CubeFace> {
+        let net_position = (x / self.face_size, y / self.face_size);
+        self.faces
+            .iter()
+            .find(|face| face.net_position == net_position)
+    }
+}
+
+impl Wrapping for CubeWrapping {
+    // Tiles are mapped to points on the surface of the cube with doubled coordinates, so the center
+    // of every tile has integer coordinates. Stepping over the edge moves half a tile outwards in the
+    // walking direction and half a tile down towards the center of the cube.
+    fn wrap(
+        &self,
+        _board: &Board,
+        position: (usize, usize),
+        facing: Facing,
+    ) -> ((usize, usize), Facing) {
+        let size = self.face_size as i32;
+        let face = self.get_face(position).unwrap();
+        let (x, y) = (
+            (position.0 % self.face_size) as i32,
+            (position.1 % self.face_size) as i32,
+        );
+        let point =
+            face.normal * size + face.right * (2 * x + 1 - size) + face.down * (2 * y + 1 - size);
+        let direction = face.get_direction(facing);
+        let new_point = point + direction - face.normal;
+
+        let new_face = self.faces.iter().find(|f| f.normal == direction).unwrap();
+        let new_facing = Facing::ALL
+            .into_iter()
+            .find(|f| new_face.get_direction(*f) == -face.normal)
+            .unwrap();
+        let (net_x, net_y) = new_face.net_position;
+        let new_x = (new_point.dot(new_face.right) + size - 1) / 2;
+        let new_y = (new_point.dot(new_face.down) + size - 1) / 2;
+        (
+            (
+                net_x * self.face_size + new_x as usize,
+                net_y * self.face_size + new_y as usize,
+            ),
+            new_facing,
+        )
+    }
+}
+
+pub fn walk(
+    board: &Board,
+    path: &[Instruction],
+    wrapping: &impl Wrapping,
+) -> Option<((usize, usize), Facing)> {
+    let mut position = board.get_start_position()?;
+    let mut facing = Facing::Right;
+    for instruction in path {
+        match instruction {
+            Instruction::TurnLeft => facing = facing.turn_left(),
+            Instruction::TurnRight => facing = facing.turn_right(),
+            Instruction::Move(steps) => {
+                for _ in 0..*steps {
+                    let (next_position, next_facing) = match board.get_neighbour(position, facing) {
+                        Some(neighbour) => (neighbour, facing),
+                        None => wrapping.wrap(board, position, facing),
+                    };
+                    if board.get_tile(next_position) == Tile::Wall {
+                        break;
+                    }
+                    position = next_position;
+                    facing = next_facing;
+                }
+            }
+        }
+    }
+    Some((position, facing))
+}
+
+pub fn get_password(((x, y), facing): ((usize, usize), Facing)) -> usize {
+    1000 * (y + 1) + 4 * (x + 1) + facing.get_value()
+}
+
+pub fn parse_map_line(line: &str) -> Result<Vec<Tile>> {
+    line.char_indices()
+        .map(|(i, tile)| match tile {
+            ' ' => Ok(Tile::Void),
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            _ => Err(ParseError::new(
+                DAY,
+                line,
+                &line[i..i + tile.len_utf8()],
+                "a tile ` `, `.` or `#`",
+            )),
+        })
+        .collect()
+}
+
+pub fn parse_path(line: &str) -> Result<Vec<Instruction>> {
+    let mut path = Vec::new();
+    let mut steps_start = None;
+    for (i, c) in line.char_indices() {
+        if c.is_ascii_digit() {
+            steps_start.get_or_insert(i);
+            continue;
+        }
+        if let Some(start) = steps_start.take() {
+            path.push(Instruction::Move(parse_token(
+                DAY,
+                line,
+                &line[start..i],
+                "a number of steps",
+            )?));
+        }
+        match c {
+            'L' => path.push(Instruction::TurnLeft),
+            'R' => path.push(Instruction::TurnRight),
+            _ => {
+                return Err(ParseError::new(
+                    DAY,
+                    line,
+                    &line[i..i + c.len_utf8()],
+                    "a number of steps, `L` or `R`",
+                ))
+            }
+        }
+    }
+    if let Some(start) = steps_start {
+        path.push(Instruction::Move(parse_token(
+            DAY,
+            line,
+            &line[start..],
+            "a number of steps",
+        )?));
+    }
+    Ok(path)
+}
+
+pub fn parse_input(input: &str) -> Result<(Board, Vec<Instruction>)> {
+    let input = &normalize_input(input);
+    let blocks = split_blocks(input);
+    let (_, map_block) = blocks[0];
+    let tiles = map_block
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_map_line(line).map_err(|e| e.shift_lines(i)))
+        .collect::<Result<Vec<Vec<Tile>>>>()?;
+    let width = tiles.iter().map(|row| row.len()).max().unwrap_or(0);
+    let board = Board { tiles, width };
+    let last_map_line = map_block.lines().last().unwrap_or(map_block);
+    if board.get_start_position().is_none() {
+        let first_line = map_block.lines().next().unwrap_or(map_block);
+        return Err(ParseError::new(
+            DAY,
+            first_line,
+            first_line,
+            "an open tile `.` to start from",
+        ));
+    }
+
+    let Some((line_offset, _)) = blocks.get(1) else {
+        return Err(ParseError::new(
+            DAY,
+            last_map_line,
+            end_of_line(last_map_line),
+            "a blank line followed by the path",
+        )
+        .shift_lines(board.get_height().saturating_sub(1)));
+    };
+    let mut path_lines = input.lines().skip(*line_offset);
+    let path_line = path_lines.next().unwrap_or_default();
+    if let Some(line) = path_lines.next() {
+        return Err(
+            ParseError::new(DAY, line, line, "the end of the input").shift_lines(line_offset + 1)
+        );
+    }
+    let path = parse_path(path_line).map_err(|e| e.shift_lines(*line_offset))?;
+
+    Ok((board, path))
+}
+
+pub struct MonkeyMap;
+
+impl Solution for MonkeyMap {
+    type Input = (Board, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((board, path): &Self::Input) -> SolveResult<Answer> {
+        Ok(get_password(walk(board, path, &FlatWrapping).unwrap()).into())
+    }
+
+    fn part2((board, path): &Self::Input) -> SolveResult<Answer> {
+        match CubeWrapping::new(board) {
+            Some(cube_wrapping) => {
+                Ok(get_password(walk(board, path, &cube_wrapping).unwrap()).into())
+            }
+            None => Err(SolveError::no_answer(
+                DAY,
+                2,
+                "the map doesn't fold into a cube",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5";
+
+    // Builds an open board from a net drawn with `x` for every face
+    fn build_open_board(net: &str, face_size: usize) -> Board {
+        let mut tiles = Vec::new();
+        for net_line in net.lines() {
+            let row = net_line
+                .chars()
+                .flat_map(|face| {
+                    let tile = if face == 'x' { Tile::Open } else { Tile::Void };
+                    vec![tile; face_size]
+                })
+                .collect::<Vec<Tile>>();
+            tiles.extend(vec![row; face_size]);
+        }
+        let width = tiles.iter().map(|row| row.len()).max().unwrap_or(0);
+        Board { tiles, width }
+    }
+
+    fn get_open_positions(board: &Board) -> Vec<(usize, usize)> {
+        (0..board.get_height())
+            .flat_map(|y| (0..board.width).map(move |x| (x, y)))
+            .filter(|position| board.get_tile(*position) == Tile::Open)
+            .collect()
+    }
+
+    #[test]
+    fn test_path_parsing() {
+        assert_eq!(
+            parse_path("10R5L").unwrap(),
+            vec![
+                Instruction::Move(10),
+                Instruction::TurnRight,
+                Instruction::Move(5),
+                Instruction::TurnLeft
+            ]
+        );
+        assert_eq!(parse_path("L").unwrap(), vec![Instruction::TurnLeft]);
+        assert_eq!(parse_path("").unwrap(), vec![]);
+        assert_eq!(parse_path("3X").unwrap_err().column, 2);
+    }
+
+    #[test]
+    fn test_input_parsing() {
+        let (board, path) = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(board.get_height(), 12);
+        assert_eq!(board.width, 16);
+        assert_eq!(board.get_tile((8, 0)), Tile::Open);
+        assert_eq!(board.get_tile((11, 0)), Tile::Wall);
+        assert_eq!(board.get_tile((0, 0)), Tile::Void);
+        assert_eq!(board.get_tile((15, 11)), Tile::Open);
+        assert_eq!(board.get_start_position(), Some((8, 0)));
+        assert_eq!(path.len(), 13);
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("..\n.x\n\n1R").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 2);
+
+        let error = parse_input("..\n..").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.expected, "a blank line followed by the path");
+
+        let error = parse_input("..\n\n1R\n2").unwrap_err();
+        assert_eq!(error.line, 4);
+        assert_eq!(error.expected, "the end of the input");
+
+        let error = parse_input("..\n\n1R2?").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 4);
+
+        let error = parse_input("##\n\n1").unwrap_err();
+        assert_eq!(error.expected, "an open tile `.` to start from");
+    }
+
+    #[test]
+    fn test_facing() {
+        assert_eq!(Facing::Right.turn_right(), Facing::Down);
+        assert_eq!(Facing::Right.turn_left(), Facing::Up);
+        assert_eq!(Facing::Up.turn_right(), Facing::Right);
+        assert_eq!(Facing::Left.reverse(), Facing::Right);
+    }
+
+    #[test]
+    fn test_flat_wrapping() {
+        let (board, _) = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(
+            FlatWrapping.wrap(&board, (11, 6), Facing::Right),
+            ((0, 6), Facing::Right)
+        );
+        assert_eq!(
+            FlatWrapping.wrap(&board, (5, 4), Facing::Up),
+            ((5, 7), Facing::Up)
+        );
+        assert_eq!(
+            FlatWrapping.wrap(&board, (8, 0), Facing::Left),
+            ((11, 0), Facing::Left)
+        );
+    }
+
+    #[test]
+    fn test_flat_walk() {
+        let (board, path) = parse_input(EXAMPLE_INPUT).unwrap();
+        let end = walk(&board, &path, &FlatWrapping).unwrap();
+        assert_eq!(end, ((7, 5), Facing::Right));
+        assert_eq!(get_password(end), 6032);
+    }
+
+    #[test]
+    fn test_cube_wrapping() {
+        let (board, _) = parse_input(EXAMPLE_INPUT).unwrap();
+        let cube_wrapping = CubeWrapping::new(&board).unwrap();
+        assert_eq!(cube_wrapping.face_size, 4);
+        // The two wraps shown in the puzzle description
+        assert_eq!(
+            cube_wrapping.wrap(&board, (11, 5), Facing::Right),
+            ((14, 8), Facing::Down)
+        );
+        assert_eq!(
+            cube_wrapping.wrap(&board, (10, 11), Facing::Down),
+            ((1, 7), Facing::Up)
+        );
+    }
+
+    #[test]
+    fn test_cube_walk() {
+        let (board, path) = parse_input(EXAMPLE_INPUT).unwrap();
+        let cube_wrapping = CubeWrapping::new(&board).unwrap();
+        let end = walk(&board, &path, &cube_wrapping).unwrap();
+        assert_eq!(end, ((6, 4), Facing::Up));
+        assert_eq!(get_password(end), 5031);
+    }
+
+    #[test]
+    fn test_cube_nets() {
+        let nets = [
+            " x  \nxxxx\n x  ",
+            "  x \nxxx \n  xx",
+            " xx\n x \nxx \nx  ",
+            "xx  \n xx \n  xx",
+            "x   \nxxxx\n   x",
+            "xxx  \n  xxx",
+            " x \nxxx\n x \n x ",
+        ];
+        for net in nets {
+            for face_size in [1, 3] {
+                let board = build_open_board(net, face_size);
+                let cube_wrapping = CubeWrapping::new(&board)
+                    .unwrap_or_else(|| panic!("Net doesn't fold:\n{}", net));
+                // Walking straight ahead around an open cube returns to the start
+                for position in get_open_positions(&board) {
+                    for facing in Facing::ALL {
+                        let mut current = (position, facing);
+                        for _ in 0..4 * face_size {
+                            current = match board.get_neighbour(current.0, current.1) {
+                                Some(neighbour) => (neighbour, current.1),
+                                None => cube_wrapping.wrap(&board, current.0, current.1),
+                            };
+                        }
+                        assert_eq!(current, (position, facing), "{}", net);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_cube_nets() {
+        for net in ["xxxxxx", "xx\nxx\nxx", "xxxx\nxx  ", "x\n\nx"] {
+            assert!(
+                CubeWrapping::new(&build_open_board(net, 2)).is_none(),
+                "{}",
+                net
+            );
+        }
+        let mut board = build_open_board("  x \nxxx \n  xx", 2);
+        board.tiles[0][4] = Tile::Void;
+        board.tiles[0].push(Tile::Open);
+        assert!(CubeWrapping::new(&board).is_none());
+    }
+
+    #[test]
+    fn test_solution() {
+        assert!(matches!(
+            MonkeyMap::solve_part_2("...\n\n1"),
+            Err(SolveError::NoAnswer {
+                day: 22,
+                part: 2,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,324 @@
+use crate::error::{ParseError, Result, SolveResult};
+use crate::parsing::normalize_input;
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+const DAY: u8 = 23;
+const ROUNDS: usize = 10;
+
+// Only the occupied positions are stored, so the grid can grow in every direction
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SparseGrid {
+    pub positions: HashSet<(i64, i64)>,
+}
+
+impl SparseGrid {
+    pub fn contains(&self, position: (i64, i64)) -> bool {
+        self.positions.contains(&position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    // Returns the smallest and largest corner of the rectangle containing all positions
+    pub fn get_bounding_box(&self) -> Option<((i64, i64), (i64, i64))> {
+        let min_x = self.positions.iter().map(|(x, _)| *x).min()?;
+        let max_x = self.positions.iter().map(|(x, _)| *x).max()?;
+        let min_y = self.positions.iter().map(|(_, y)| *y).min()?;
+        let max_y = self.positions.iter().map(|(_, y)| *y).max()?;
+        Some(((min_x, min_y), (max_x, max_y)))
+    }
+
+    pub fn count_empty_in_bounding_box(&self) -> usize {
+        match self.get_bounding_box() {
+            Some(((min_x, min_y), (max_x, max_y))) => {
+                ((max_x - min_x + 1) * (max_y - min_y + 1)) as usize - self.len()
+            }
+            None => 0,
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let Some(((min_x, min_y), (max_x, max_y))) = self.get_bounding_box() else {
+            return String::new();
+        };
+        let mut rendering = String::new();
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                rendering.push(if self.contains((x, y)) { '#' } else { '.' });
+            }
+            rendering.push('\n');
+        }
+        rendering
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Direction {
+    // The order of the proposals in the first round, which moves the first direction to the end
+    // after every round
+    pub const PROPOSAL_ORDER: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ];
+
+    // The position in the direction, followed by its two diagonal neighbours
+    pub fn get_checked_positions(&self, (x, y): (i64, i64)) -> [(i64, i64); 3] {
+        match self {
+            Direction::North => [(x, y - 1), (x - 1, y - 1), (x + 1, y - 1)],
+            Direction::South => [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)],
+            Direction::West => [(x - 1, y), (x - 1, y - 1), (x - 1, y + 1)],
+            Direction::East => [(x + 1, y), (x + 1, y - 1), (x + 1, y + 1)],
+        }
+    }
+}
+
+pub fn get_neighbours((x, y): (i64, i64)) -> [(i64, i64); 8] {
+    [
+        (x - 1, y - 1),
+        (x, y - 1),
+        (x + 1, y - 1),
+        (x - 1, y),
+        (x + 1, y),
+        (x - 1, y + 1),
+        (x, y + 1),
+        (x + 1, y + 1),
+    ]
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Diffusion {
+    pub grid: SparseGrid,
+    pub round: usize,
+}
+
+impl Diffusion {
+    pub fn new(grid: SparseGrid) -> Self {
+        Self { grid, round: 0 }
+    }
+
+    fn propose_move(&self, elf: (i64, i64)) -> Option<(i64, i64)> {
+        if !get_neighbours(elf)
+            .iter()
+            .any(|neighbour| self.grid.contains(*neighbour))
+        {
+            return None;
+        }
+        (0..4)
+            .map(|i| Direction::PROPOSAL_ORDER[(self.round + i) % 4].get_checked_positions(elf))
+            .find(|checked_positions| {
+                checked_positions
+                    .iter()
+                    .all(|position| !self.grid.contains(*position))
+            })
+            .map(|checked_positions| checked_positions[0])
+    }
+
+    // Runs a round and returns whether any elf moved
+    pub fn run_round(&mut self) -> bool {
+        let mut proposals: HashMap<(i64, i64), Vec<(i64, i64)>> = HashMap::new();
+        for elf in &self.grid.positions {
+            if let Some(proposal) = self.propose_move(*elf) {
+                proposals.entry(proposal).or_default().push(*elf);
+            }
+        }
+
+        let mut moved = false;
+        for (proposal, elves) in proposals {
+            if let [elf] = elves[..] {
+                self.grid.positions.remove(&elf);
+                self.grid.positions.insert(proposal);
+                moved = true;
+            }
+        }
+        self.round += 1;
+        moved
+    }
+
+    // Returns the rendered grid after every round
+    pub fn run_rounds_with_snapshots(&mut self, rounds: usize) -> Vec<String> {
+        (0..rounds)
+            .map(|_| {
+                self.run_round();
+                self.grid.render()
+            })
+            .collect()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<SparseGrid> {
+    let input = &normalize_input(input);
+    let mut grid = SparseGrid::default();
+    for (y, line) in input.lines().enumerate() {
+        for (x, tile) in line.char_indices() {
+            match tile {
+                '#' => {
+                    grid.positions.insert((x as i64, y as i64));
+                }
+                '.' => {}
+                _ => {
+                    return Err(ParseError::new(
+                        DAY,
+                        line,
+                        &line[x..x + tile.len_utf8()],
+                        "an elf `#` or ground `.`",
+                    )
+                    .shift_lines(y))
+                }
+            }
+        }
+    }
+    Ok(grid)
+}
+
+pub fn count_empty_tiles_after_rounds(grid: &SparseGrid, rounds: usize) -> usize {
+    let mut diffusion = Diffusion::new(grid.clone());
+    for _ in 0..rounds {
+        diffusion.run_round();
+    }
+    diffusion.grid.count_empty_in_bounding_box()
+}
+
+pub fn find_first_round_without_movement(grid: &SparseGrid) -> usize {
+    let mut diffusion = Diffusion::new(grid.clone());
+    while diffusion.run_round() {}
+    diffusion.round
+}
+
+pub struct UnstableDiffusion;
+
+impl Solution for UnstableDiffusion {
+    type Input = SparseGrid;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> SolveResult<Answer> {
+        Ok(count_empty_tiles_after_rounds(grid, ROUNDS).into())
+    }
+
+    fn part2(grid: &Self::Input) -> SolveResult<Answer> {
+        Ok(find_first_round_without_movement(grid).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE_INPUT: &str = ".....
+        ..##.
+        ..#..
+        .....
+        ..##.
+        .....";
+
+    const EXAMPLE_INPUT: &str = "....#..
+        ..###.#
+        #...#.#
+        .#...##
+        #.###..
+        ##.#.##
+        .#..#..";
+
+    fn unindent(text: &str) -> String {
+        text.lines()
+            .map(|line| format!("{}\n", line.trim()))
+            .collect()
+    }
+
+    #[test]
+    fn test_input_parsing() {
+        let grid = parse_input(&unindent(SMALL_EXAMPLE_INPUT)).unwrap();
+        assert_eq!(
+            grid.positions,
+            HashSet::from([(2, 1), (3, 1), (2, 2), (2, 4), (3, 4)])
+        );
+        assert_eq!(parse_input("..\n.x").unwrap_err().column, 2);
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let grid = parse_input(&unindent(SMALL_EXAMPLE_INPUT)).unwrap();
+        assert_eq!(grid.get_bounding_box(), Some(((2, 1), (3, 4))));
+        assert_eq!(grid.count_empty_in_bounding_box(), 3);
+        assert_eq!(grid.render(), "##\n#.\n..\n##\n");
+        assert_eq!(SparseGrid::default().render(), "");
+        assert_eq!(SparseGrid::default().count_empty_in_bounding_box(), 0);
+    }
+
+    #[test]
+    fn test_small_example_snapshots() {
+        let grid = parse_input(&unindent(SMALL_EXAMPLE_INPUT)).unwrap();
+        let mut diffusion = Diffusion::new(grid);
+        assert_eq!(
+            diffusion.run_rounds_with_snapshots(3),
+            vec![
+                "##\n..\n#.\n.#\n#.\n",
+                ".##.\n#...\n...#\n....\n.#..\n",
+                "..#..\n....#\n#....\n....#\n.....\n..#..\n",
+            ]
+        );
+        assert!(!diffusion.run_round());
+        assert_eq!(diffusion.round, 4);
+    }
+
+    #[test]
+    fn test_example_snapshots() {
+        let grid = parse_input(&unindent(EXAMPLE_INPUT)).unwrap();
+        let mut diffusion = Diffusion::new(grid);
+        let snapshots = diffusion.run_rounds_with_snapshots(10);
+        assert_eq!(
+            snapshots[0],
+            unindent(
+                ".....#...
+                ...#...#.
+                .#..#.#..
+                .....#..#
+                ..#.#.##.
+                #..#.#...
+                #.#.#.##.
+                .........
+                ..#..#..."
+            )
+        );
+        assert_eq!(
+            snapshots[9],
+            unindent(
+                "......#.....
+                ..........#.
+                .#.#..#.....
+                .....#......
+                ..#.....#..#
+                #......##...
+                ....##......
+                .#........#.
+                ...#.#..#...
+                ............
+                ...#..#..#.."
+            )
+        );
+    }
+
+    #[test]
+    fn test_rounds() {
+        let grid = parse_input(&unindent(EXAMPLE_INPUT)).unwrap();
+        assert_eq!(count_empty_tiles_after_rounds(&grid, 10), 110);
+        assert_eq!(find_first_round_without_movement(&grid), 20);
+    }
+}
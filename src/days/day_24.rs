@@ -0,0 +1,482 @@
+use crate::error::{ParseError, Result, SolveError, SolveResult};
+use crate::parsing::{end_of_line, normalize_input};
+use crate::solution::{Answer, Solution};
+use std::collections::{HashSet, VecDeque};
+
+const DAY: u8 = 24;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Blizzard {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Blizzard {
+    pub fn from_char(tile: char) -> Option<Self> {
+        match tile {
+            '^' => Some(Blizzard::Up),
+            'v' => Some(Blizzard::Down),
+            '<' => Some(Blizzard::Left),
+            '>' => Some(Blizzard::Right),
+            _ => None,
+        }
+    }
+}
+
+// Positions are map coordinates including the surrounding walls, so the blizzards start inside
+// the `width` by `height` area from (1, 1)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Valley {
+    pub width: usize,
+    pub height: usize,
+    pub start: (usize, usize),
+    pub goal: (usize, usize),
+    pub blizzards: Vec<Vec<Option<Blizzard>>>,
+}
+
+fn get_greatest_common_divisor(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        get_greatest_common_divisor(b, a % b)
+    }
+}
+
+fn get_least_common_multiple(a: usize, b: usize) -> usize {
+    a / get_greatest_common_divisor(a, b) * b
+}
+
+// Every blizzard returns to its starting tile after `width` or `height` minutes, so the whole
+// field repeats after the least common multiple of both. The occupied tiles are precomputed for
+// every minute of one period.
+#[derive(Clone, Debug)]
+pub struct BlizzardField {
+    pub width: usize,
+    pub height: usize,
+    pub period: usize,
+    start: (usize, usize),
+    goal: (usize, usize),
+    occupied: Vec<Vec<bool>>,
+}
+
+impl BlizzardField {
+    pub fn new(valley: &Valley) -> Self {
+        let (width, height) = (valley.width, valley.height);
+        let period = get_least_common_multiple(width, height);
+        let occupied = (0..period)
+            .map(|time| {
+                let mut occupied = vec![false; width * height];
+                for (y, row) in valley.blizzards.iter().enumerate() {
+                    for (x, blizzard) in row.iter().enumerate() {
+                        let (x, y) = match blizzard {
+                            Some(Blizzard::Up) => (x, (y + height - time % height) % height),
+                            Some(Blizzard::Down) => (x, (y + time) % height),
+                            Some(Blizzard::Left) => ((x + width - time % width) % width, y),
+                            Some(Blizzard::Right) => ((x + time) % width, y),
+                            None => continue,
+                        };
+                        occupied[y * width + x] = true;
+                    }
+                }
+                occupied
+            })
+            .collect();
+
+        Self {
+            width,
+            height,
+            period,
+            start: valley.start,
+            goal: valley.goal,
+            occupied,
+        }
+    }
+
+    pub fn has_blizzard_at(&self, (x, y): (usize, usize), time: usize) -> bool {
+        (1..=self.width).contains(&x)
+            && (1..=self.height).contains(&y)
+            && self.occupied[time % self.period][(y - 1) * self.width + x - 1]
+    }
+
+    pub fn is_open(&self, position: (usize, usize), time: usize) -> bool {
+        let (x, y) = position;
+        if position == self.start || position == self.goal {
+            return true;
+        }
+        (1..=self.width).contains(&x)
+            && (1..=self.height).contains(&y)
+            && !self.has_blizzard_at(position, time)
+    }
+
+    // Waiting in place followed by the four orthogonal moves
+    fn get_moves(&self, (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+        let mut moves = vec![(x, y), (x + 1, y), (x, y + 1)];
+        if x > 0 {
+            moves.push((x - 1, y));
+        }
+        if y > 0 {
+            moves.push((x, y - 1));
+        }
+        moves
+    }
+
+    // Breadth-first search over the position and the minute within the period, so every state
+    // is visited at the earliest minute it can be reached. Returns the minute of arrival.
+    pub fn find_fastest_crossing(
+        &self,
+        from: (usize, usize),
+        to: (usize, usize),
+        start_time: usize,
+    ) -> Option<usize> {
+        let mut search_queue = VecDeque::from([(from, start_time)]);
+        let mut visited = HashSet::from([(from, start_time % self.period)]);
+
+        while let Some((position, time)) = search_queue.pop_front() {
+            if position == to {
+                return Some(time);
+            }
+            for next_position in self.get_moves(position) {
+                if self.is_open(next_position, time + 1)
+                    && visited.insert((next_position, (time + 1) % self.period))
+                {
+                    search_queue.push_back((next_position, time + 1));
+                }
+            }
+        }
+        None
+    }
+
+    // Crosses the valley between consecutive waypoints, starting each leg when the previous one
+    // arrived
+    pub fn find_fastest_trip(&self, waypoints: &[(usize, usize)]) -> Option<usize> {
+        waypoints.windows(2).try_fold(0, |time, leg| {
+            self.find_fastest_crossing(leg[0], leg[1], time)
+        })
+    }
+}
+
+fn get_tile(line: &str, x: usize, tile: char) -> &str {
+    &line[x..x + tile.len_utf8()]
+}
+
+// Returns the column of the single gap in a wall row
+fn parse_wall_row(line: &str, width: usize) -> Result<usize> {
+    let mut gap = None;
+    for (x, tile) in line.char_indices() {
+        match tile {
+            '#' if x <= width + 1 => {}
+            '.' if gap.is_none() && (1..=width).contains(&x) => gap = Some(x),
+            _ if x > width + 1 => {
+                return Err(ParseError::new(
+                    DAY,
+                    line,
+                    get_tile(line, x, tile),
+                    "the end of the row",
+                ))
+            }
+            _ => {
+                return Err(ParseError::new(
+                    DAY,
+                    line,
+                    get_tile(line, x, tile),
+                    "a wall `#` with a single gap `.`",
+                ))
+            }
+        }
+    }
+    if line.len() < width + 2 {
+        return Err(ParseError::new(
+            DAY,
+            line,
+            end_of_line(line),
+            "a wall `#` at the end of the row",
+        ));
+    }
+    gap.ok_or_else(|| ParseError::new(DAY, line, end_of_line(line), "a gap `.` in the wall"))
+}
+
+fn parse_valley_row(line: &str, width: usize) -> Result<Vec<Option<Blizzard>>> {
+    let mut row = Vec::new();
+    for (x, tile) in line.char_indices() {
+        if x > width + 1 {
+            return Err(ParseError::new(
+                DAY,
+                line,
+                get_tile(line, x, tile),
+                "the end of the row",
+            ));
+        } else if x == 0 || x == width + 1 {
+            if tile != '#' {
+                return Err(ParseError::new(
+                    DAY,
+                    line,
+                    get_tile(line, x, tile),
+                    "a wall `#`",
+                ));
+            }
+        } else if tile == '.' {
+            row.push(None);
+        } else if let Some(blizzard) = Blizzard::from_char(tile) {
+            row.push(Some(blizzard));
+        } else {
+            return Err(ParseError::new(
+                DAY,
+                line,
+                get_tile(line, x, tile),
+                "ground `.` or a blizzard `^`, `v`, `<` or `>`",
+            ));
+        }
+    }
+    if line.len() < width + 2 {
+        return Err(ParseError::new(
+            DAY,
+            line,
+            end_of_line(line),
+            "a wall `#` at the end of the row",
+        ));
+    }
+    Ok(row)
+}
+
+pub fn parse_input(input: &str) -> Result<Valley> {
+    let input = &normalize_input(input);
+    let lines = input.lines().collect::<Vec<&str>>();
+    let first_line = lines.first().copied().unwrap_or(input);
+    let width = first_line.chars().count().saturating_sub(2);
+    if width == 0 {
+        return Err(ParseError::new(
+            DAY,
+            first_line,
+            end_of_line(first_line),
+            "a wall of at least 3 tiles",
+        ));
+    }
+    if lines.len() < 3 {
+        let last_line = lines[lines.len() - 1];
+        return Err(ParseError::new(
+            DAY,
+            last_line,
+            end_of_line(last_line),
+            "a valley with at least one row between the walls",
+        )
+        .shift_lines(lines.len() - 1));
+    }
+
+    let height = lines.len() - 2;
+    let start = (parse_wall_row(first_line, width)?, 0);
+    let blizzards = lines[1..=height]
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse_valley_row(line, width).map_err(|e| e.shift_lines(i + 1)))
+        .collect::<Result<Vec<_>>>()?;
+    let goal = (
+        parse_wall_row(lines[height + 1], width).map_err(|e| e.shift_lines(height + 1))?,
+        height + 1,
+    );
+
+    Ok(Valley {
+        width,
+        height,
+        start,
+        goal,
+        blizzards,
+    })
+}
+
+fn get_trip_answer(trip_time: Option<usize>, part: usize) -> SolveResult<Answer> {
+    match trip_time {
+        Some(time) => Ok(time.into()),
+        None => Err(SolveError::no_answer(
+            DAY,
+            part,
+            "the goal can't be reached",
+        )),
+    }
+}
+
+pub struct BlizzardBasin;
+
+impl Solution for BlizzardBasin {
+    type Input = Valley;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(valley: &Self::Input) -> SolveResult<Answer> {
+        let field = BlizzardField::new(valley);
+        get_trip_answer(field.find_fastest_trip(&[valley.start, valley.goal]), 1)
+    }
+
+    fn part2(valley: &Self::Input) -> SolveResult<Answer> {
+        let field = BlizzardField::new(valley);
+        let trip = [valley.start, valley.goal, valley.start, valley.goal];
+        get_trip_answer(field.find_fastest_trip(&trip), 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIMPLE_EXAMPLE_INPUT: &str = "#.#####
+#.....#
+#>....#
+#.....#
+#...v.#
+#.....#
+#####.#";
+
+    const EXAMPLE_INPUT: &str = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+    #[test]
+    fn test_input_parsing() {
+        let valley = parse_input(SIMPLE_EXAMPLE_INPUT).unwrap();
+        assert_eq!((valley.width, valley.height), (5, 5));
+        assert_eq!(valley.start, (1, 0));
+        assert_eq!(valley.goal, (5, 6));
+        assert_eq!(
+            valley.blizzards[1],
+            vec![Some(Blizzard::Right), None, None, None, None]
+        );
+        assert_eq!(valley.blizzards[3][3], Some(Blizzard::Down));
+
+        let valley = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!((valley.width, valley.height), (6, 4));
+        assert_eq!(valley.goal, (6, 5));
+    }
+
+    #[test]
+    fn test_input_parsing_errors() {
+        let error = parse_input("#.###\n#.x.#\n###.#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(
+            error.expected,
+            "ground `.` or a blizzard `^`, `v`, `<` or `>`"
+        );
+
+        let error = parse_input("#.###\n#...#.\n###.#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.expected, "the end of the row");
+
+        let error = parse_input("#.###\n#...\n###.#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.expected, "a wall `#` at the end of the row");
+
+        let error = parse_input("#.###\n....#\n###.#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a wall `#`");
+
+        let error = parse_input("#..##\n#...#\n###.#").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.expected, "a wall `#` with a single gap `.`");
+
+        let error = parse_input("#.###\n#...#\n#####").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
+        assert_eq!(error.expected, "a gap `.` in the wall");
+
+        let error = parse_input("#.###\n###.#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(
+            error.expected,
+            "a valley with at least one row between the walls"
+        );
+
+        let error = parse_input("#.").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.expected, "a wall of at least 3 tiles");
+    }
+
+    #[test]
+    fn test_blizzard_field() {
+        let field = BlizzardField::new(&parse_input(SIMPLE_EXAMPLE_INPUT).unwrap());
+        assert_eq!(field.period, 5);
+        assert!(field.has_blizzard_at((1, 2), 0));
+        assert!(field.has_blizzard_at((4, 4), 0));
+        assert!(field.has_blizzard_at((2, 2), 1));
+        assert!(field.has_blizzard_at((4, 5), 1));
+        // Both blizzards share a tile after three minutes, once the down one has wrapped around
+        assert!(field.has_blizzard_at((4, 2), 3));
+        assert_eq!(
+            (1..=5)
+                .flat_map(|x| (1..=5).map(move |y| (x, y)))
+                .filter(|position| field.has_blizzard_at(*position, 3))
+                .count(),
+            1
+        );
+        assert!(field.has_blizzard_at((1, 2), 5));
+        assert!(field.has_blizzard_at((4, 4), 10));
+
+        let field = BlizzardField::new(&parse_input(EXAMPLE_INPUT).unwrap());
+        assert_eq!(field.period, 12);
+        assert!(field.is_open((1, 0), 0));
+        assert!(field.is_open((6, 5), 0));
+        assert!(!field.is_open((0, 1), 0));
+        assert!(!field.is_open((1, 1), 0));
+        assert!(field.is_open((1, 1), 1));
+    }
+
+    #[test]
+    fn test_period_lengths() {
+        assert_eq!(get_least_common_multiple(6, 4), 12);
+        assert_eq!(get_least_common_multiple(120, 25), 600);
+        assert_eq!(get_least_common_multiple(5, 5), 5);
+        assert_eq!(get_least_common_multiple(7, 1), 7);
+    }
+
+    #[test]
+    fn test_fastest_crossing() {
+        let valley = parse_input(EXAMPLE_INPUT).unwrap();
+        let field = BlizzardField::new(&valley);
+        assert_eq!(
+            field.find_fastest_crossing(valley.start, valley.goal, 0),
+            Some(18)
+        );
+        assert_eq!(
+            field.find_fastest_crossing(valley.goal, valley.start, 18),
+            Some(41)
+        );
+        assert_eq!(
+            field.find_fastest_crossing(valley.start, valley.goal, 41),
+            Some(54)
+        );
+        assert_eq!(
+            field.find_fastest_trip(&[valley.start, valley.goal, valley.start, valley.goal]),
+            Some(54)
+        );
+        assert_eq!(field.find_fastest_trip(&[valley.start]), Some(0));
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        // The only tile in the valley is always taken by a blizzard
+        let valley = parse_input("#.#\n#>#\n#.#").unwrap();
+        let field = BlizzardField::new(&valley);
+        assert_eq!(
+            field.find_fastest_crossing(valley.start, valley.goal, 0),
+            None
+        );
+        assert!(matches!(
+            BlizzardBasin::part1(&valley),
+            Err(SolveError::NoAnswer {
+                day: 24,
+                part: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_solution() {
+        let valley = BlizzardBasin::parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(BlizzardBasin::part1(&valley), Ok(Answer::from(18)));
+        assert_eq!(BlizzardBasin::part2(&valley), Ok(Answer::from(54)));
+    }
+}
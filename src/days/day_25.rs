@@ -0,0 +1,379 @@
+use crate::error::{Result, SolveError, SolveResult};
+use crate::parsing::{normalize_input, parse_token};
+use crate::solution::{Answer, Solution};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+const DAY: u8 = 25;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SnafuError {
+    Empty,
+    InvalidDigit(char),
+    Overflow,
+}
+
+impl fmt::Display for SnafuError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "a SNAFU number needs at least one digit"),
+            Self::InvalidDigit(c) => write!(f, "'{}' is not a SNAFU digit", c),
+            Self::Overflow => write!(f, "the SNAFU number doesn't fit into an i64"),
+        }
+    }
+}
+
+impl std::error::Error for SnafuError {}
+
+// A balanced base-5 number with the digits `=`, `-`, `0`, `1` and `2` for -2 to 2. The digits are
+// stored from the least significant one without leading zeros, so the numbers have no size limit
+// and only the conversion to an i64 can overflow.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Snafu {
+    digits: Vec<i8>,
+}
+
+impl Snafu {
+    fn from_digits(mut digits: Vec<i8>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Self { digits }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+}
+
+fn get_digit_value(digit: char) -> Option<i8> {
+    match digit {
+        '=' => Some(-2),
+        '-' => Some(-1),
+        '0' => Some(0),
+        '1' => Some(1),
+        '2' => Some(2),
+        _ => None,
+    }
+}
+
+fn get_digit_char(value: i8) -> char {
+    match value {
+        -2 => '=',
+        -1 => '-',
+        0 => '0',
+        1 => '1',
+        2 => '2',
+        _ => unreachable!("SNAFU digits are between -2 and 2"),
+    }
+}
+
+// Splits a value into a balanced base-5 digit and the carry into the next digit
+fn split_balanced_digit(value: i128) -> (i8, i128) {
+    let digit = (value + 2).rem_euclid(5) - 2;
+    (digit as i8, (value - digit) / 5)
+}
+
+impl FromStr for Snafu {
+    type Err = SnafuError;
+
+    fn from_str(text: &str) -> std::result::Result<Self, Self::Err> {
+        if text.is_empty() {
+            return Err(SnafuError::Empty);
+        }
+        let digits = text
+            .chars()
+            .rev()
+            .map(|c| get_digit_value(c).ok_or(SnafuError::InvalidDigit(c)))
+            .collect::<std::result::Result<Vec<i8>, SnafuError>>()?;
+        Ok(Self::from_digits(digits))
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let text = self
+            .digits
+            .iter()
+            .rev()
+            .map(|digit| get_digit_char(*digit))
+            .collect::<String>();
+        write!(f, "{}", text)
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(value: i64) -> Self {
+        // Computed in an i128, since the carry of i64::MIN doesn't fit into an i64
+        let mut value = value as i128;
+        let mut digits = Vec::new();
+        while value != 0 {
+            let (digit, carry) = split_balanced_digit(value);
+            digits.push(digit);
+            value = carry;
+        }
+        Self { digits }
+    }
+}
+
+impl TryFrom<&Snafu> for i64 {
+    type Error = SnafuError;
+
+    fn try_from(snafu: &Snafu) -> std::result::Result<Self, Self::Error> {
+        // The partial sums can leave the i64 range by a little even if the result doesn't
+        let value = snafu.digits.iter().rev().try_fold(0i128, |value, digit| {
+            value.checked_mul(5)?.checked_add(*digit as i128)
+        });
+        value
+            .and_then(|value| i64::try_from(value).ok())
+            .ok_or(SnafuError::Overflow)
+    }
+}
+
+impl TryFrom<Snafu> for i64 {
+    type Error = SnafuError;
+
+    fn try_from(snafu: Snafu) -> std::result::Result<Self, Self::Error> {
+        i64::try_from(&snafu)
+    }
+}
+
+impl Add<&Snafu> for &Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: &Snafu) -> Snafu {
+        let length = self.digits.len().max(other.digits.len());
+        let mut digits = Vec::with_capacity(length + 1);
+        let mut carry = 0;
+        for i in 0..length {
+            let left = self.digits.get(i).copied().unwrap_or(0);
+            let right = other.digits.get(i).copied().unwrap_or(0);
+            let (digit, next_carry) = split_balanced_digit((left + right) as i128 + carry);
+            digits.push(digit);
+            carry = next_carry;
+        }
+        digits.push(carry as i8);
+        Snafu::from_digits(digits)
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: Snafu) -> Snafu {
+        &self + &other
+    }
+}
+
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |sum, snafu| sum + snafu)
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |sum, snafu| &sum + snafu)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Snafu>> {
+    let input = &normalize_input(input);
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            parse_token(
+                DAY,
+                line,
+                line.trim(),
+                "a SNAFU number of the digits `=-012`",
+            )
+            .map_err(|e| e.shift_lines(i))
+        })
+        .collect()
+}
+
+pub struct FullOfHotAir;
+
+impl Solution for FullOfHotAir {
+    type Input = Vec<Snafu>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(numbers: &Self::Input) -> SolveResult<Answer> {
+        Ok(numbers.iter().sum::<Snafu>().to_string().into())
+    }
+
+    fn part2(_numbers: &Self::Input) -> SolveResult<Answer> {
+        Err(SolveError::not_applicable(DAY, 2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "1=-0-2
+        12111
+        2=0=
+        21
+        2=01
+        111
+        20012
+        112
+        1=-1=
+        1-12
+        12
+        1=
+        122";
+
+    const EXAMPLE_VALUES: [(i64, &str); 15] = [
+        (1, "1"),
+        (2, "2"),
+        (3, "1="),
+        (4, "1-"),
+        (5, "10"),
+        (6, "11"),
+        (7, "12"),
+        (8, "2="),
+        (9, "2-"),
+        (10, "20"),
+        (15, "1=0"),
+        (20, "1-0"),
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+    ];
+
+    // A xorshift generator, so the property tests are repeatable without extra dependencies
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        // Mixes values of every magnitude, since uniform values would almost all be huge
+        fn next_i64(&mut self) -> i64 {
+            let shift = self.next() % 64;
+            (self.next() as i64) >> shift
+        }
+    }
+
+    // Negating a balanced number negates every digit
+    fn negate_text(text: &str) -> String {
+        text.chars()
+            .map(|c| match c {
+                '=' => '2',
+                '-' => '1',
+                '1' => '-',
+                '2' => '=',
+                c => c,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_input_parsing() {
+        let numbers = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(numbers.len(), 13);
+        assert_eq!(numbers[0].to_string(), "1=-0-2");
+
+        let error = parse_input("1=\n1x2").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a SNAFU number of the digits `=-012`");
+    }
+
+    #[test]
+    fn test_snafu_parsing() {
+        assert_eq!("".parse::<Snafu>(), Err(SnafuError::Empty));
+        assert_eq!("12a".parse::<Snafu>(), Err(SnafuError::InvalidDigit('a')));
+        assert_eq!("0".parse::<Snafu>(), Ok(Snafu::default()));
+        assert_eq!("0012".parse::<Snafu>().unwrap().to_string(), "12");
+        assert_eq!(Snafu::default().to_string(), "0");
+    }
+
+    #[test]
+    fn test_example_conversions() {
+        for (value, text) in EXAMPLE_VALUES {
+            assert_eq!(Snafu::from(value).to_string(), text);
+            assert_eq!(i64::try_from(text.parse::<Snafu>().unwrap()), Ok(value));
+            assert_eq!(Snafu::from(-value).to_string(), negate_text(text));
+        }
+    }
+
+    #[test]
+    fn test_conversion_limits() {
+        for value in [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX] {
+            assert_eq!(i64::try_from(Snafu::from(value)), Ok(value));
+        }
+        let too_large = &Snafu::from(i64::MAX) + &Snafu::from(1);
+        assert_eq!(i64::try_from(&too_large), Err(SnafuError::Overflow));
+        let too_small = &Snafu::from(i64::MIN) + &Snafu::from(-1);
+        assert_eq!(i64::try_from(&too_small), Err(SnafuError::Overflow));
+        let huge = "1".repeat(100).parse::<Snafu>().unwrap();
+        assert_eq!(i64::try_from(&huge), Err(SnafuError::Overflow));
+    }
+
+    #[test]
+    fn test_round_trip_property() {
+        let mut random = Random(0x2545f4914f6cdd1d);
+        for _ in 0..10000 {
+            let value = random.next_i64();
+            let snafu = Snafu::from(value);
+            let text = snafu.to_string();
+            assert!(text == "0" || !text.starts_with('0'));
+            let parsed = text.parse::<Snafu>().unwrap();
+            assert_eq!(parsed, snafu);
+            assert_eq!(i64::try_from(&parsed), Ok(value));
+        }
+    }
+
+    #[test]
+    fn test_addition_property() {
+        let mut random = Random(0x9e3779b97f4a7c15);
+        for _ in 0..10000 {
+            let (left, right) = (random.next_i64(), random.next_i64());
+            let sum = Snafu::from(left) + Snafu::from(right);
+            match left.checked_add(right) {
+                Some(value) => assert_eq!(i64::try_from(&sum), Ok(value)),
+                None => assert_eq!(i64::try_from(&sum), Err(SnafuError::Overflow)),
+            }
+            assert_eq!(sum, Snafu::from(right) + Snafu::from(left));
+        }
+    }
+
+    #[test]
+    fn test_sum() {
+        let numbers = parse_input(EXAMPLE_INPUT).unwrap();
+        let sum = numbers.iter().sum::<Snafu>();
+        assert_eq!(sum.to_string(), "2=-1=0");
+        assert_eq!(i64::try_from(&sum), Ok(4890));
+        assert_eq!(numbers.into_iter().sum::<Snafu>(), sum);
+        assert_eq!(
+            Vec::<Snafu>::new().into_iter().sum::<Snafu>(),
+            Snafu::default()
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let numbers = FullOfHotAir::parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(FullOfHotAir::part1(&numbers), Ok(Answer::from("2=-1=0")));
+        assert_eq!(
+            FullOfHotAir::part2(&numbers),
+            Err(SolveError::not_applicable(25, 2))
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub line_text: String,
+    pub text: String,
+    pub expected: String,
+}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+impl ParseError {
+    // The text has to be a slice of the line to determine its column, the line number is relative
+    // to the given line until it is moved via shift_lines
+    pub fn new(day: u8, line: &str, text: &str, expected: &str) -> Self {
+        let column = match get_offset(line, text) {
+            Some(offset) => line[..offset].chars().count() + 1,
+            None => 1,
+        };
+        Self {
+            day,
+            line: 1,
+            column,
+            line_text: String::from(line),
+            text: String::from(text),
+            expected: String::from(expected),
+        }
+    }
+
+    pub fn shift_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let found = if self.text.is_empty() {
+            String::from("nothing")
+        } else {
+            format!("`{}`", self.text)
+        };
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let marker = "^".repeat(self.text.chars().count().max(1));
+        writeln!(f, "error: expected {}, found {}", self.expected, found)?;
+        writeln!(
+            f,
+            "{}--> day {}, line {}, column {}",
+            gutter, self.day, self.line, self.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line_number, self.line_text)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), marker)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// A part can fail after a successful parse, e.g. if the input has no answer for it
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SolveError {
+    Parse(ParseError),
+    NoAnswer {
+        day: u8,
+        part: usize,
+        reason: String,
+    },
+    // The puzzle itself has no such part, like the second part of the last day
+    NotApplicable {
+        day: u8,
+        part: usize,
+    },
+}
+
+pub type SolveResult<T> = std::result::Result<T, SolveError>;
+
+impl SolveError {
+    pub fn no_answer(day: u8, part: usize, reason: &str) -> Self {
+        Self::NoAnswer {
+            day,
+            part,
+            reason: String::from(reason),
+        }
+    }
+
+    pub fn not_applicable(day: u8, part: usize) -> Self {
+        Self::NotApplicable { day, part }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse(error) => write!(f, "{}", error),
+            Self::NoAnswer { day, part, reason } => {
+                write!(
+                    f,
+                    "error: day {}, part {} has no answer: {}",
+                    day, part, reason
+                )
+            }
+            Self::NotApplicable { day, part } => {
+                write!(f, "day {} has no part {} puzzle", day, part)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+fn get_offset(outer: &str, inner: &str) -> Option<usize> {
+    let outer_start = outer.as_ptr() as usize;
+    let inner_start = inner.as_ptr() as usize;
+    if inner_start >= outer_start && inner_start + inner.len() <= outer_start + outer.len() {
+        Some(inner_start - outer_start)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_column() {
+        let line = "move 1 from x to 3";
+        let error = ParseError::new(5, line, &line[12..13], "an integer");
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 13);
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn test_error_column_of_foreign_text() {
+        let error = ParseError::new(5, "move 1 from x to 3", "x", "an integer");
+        assert_eq!(error.column, 1);
+    }
+
+    #[test]
+    fn test_shift_lines() {
+        let error = ParseError::new(1, "a", "a", "an integer").shift_lines(4);
+        assert_eq!(error.line, 5);
+    }
+
+    #[test]
+    fn test_error_display() {
+        let input = "noop\naddx x";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::new(10, line, &line[5..], "an integer").shift_lines(1);
+        assert_eq!(
+            error.to_string(),
+            "error: expected an integer, found `x`
+ --> day 10, line 2, column 6
+  |
+2 | addx x
+  |      ^"
+        );
+    }
+
+    #[test]
+    fn test_solve_error_display() {
+        let error = SolveError::no_answer(22, 2, "the map doesn't fold into a cube");
+        assert_eq!(
+            error.to_string(),
+            "error: day 22, part 2 has no answer: the map doesn't fold into a cube"
+        );
+        let parse_error = ParseError::new(10, "addx", "", "an integer");
+        assert_eq!(
+            SolveError::from(parse_error.clone()).to_string(),
+            parse_error.to_string()
+        );
+        assert_eq!(
+            SolveError::not_applicable(25, 2).to_string(),
+            "day 25 has no part 2 puzzle"
+        );
+    }
+
+    #[test]
+    fn test_error_display_at_end_of_line() {
+        let line = "addx";
+        let error = ParseError::new(10, line, &line[4..], "an integer");
+        assert_eq!(
+            error.to_string(),
+            "error: expected an integer, found nothing
+ --> day 10, line 1, column 5
+  |
+1 | addx
+  |     ^"
+        );
+    }
+}
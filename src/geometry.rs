@@ -0,0 +1,472 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+// The difference is taken from the larger value, so unsigned coordinates don't underflow
+fn get_absolute_difference<T: Copy + PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point2<T> {
+    pub fn manhattan_distance(&self, other: Point2<T>) -> T {
+        get_absolute_difference(self.x, other.x) + get_absolute_difference(self.y, other.y)
+    }
+
+    // The number of king moves between the points, so diagonal neighbours have a distance of 1
+    pub fn chebyshev_distance(&self, other: Point2<T>) -> T {
+        let d_x = get_absolute_difference(self.x, other.x);
+        let d_y = get_absolute_difference(self.y, other.y);
+        if d_x > d_y {
+            d_x
+        } else {
+            d_y
+        }
+    }
+}
+
+impl<T: Copy + PartialOrd> Point2<T> {
+    // Clamps every coordinate on its own, unlike `Ord::clamp` which compares whole points
+    pub fn clamp_components(self, min: Point2<T>, max: Point2<T>) -> Self {
+        let clamp_value = |value: T, min: T, max: T| {
+            if value < min {
+                min
+            } else if value > max {
+                max
+            } else {
+                value
+            }
+        };
+        Self::new(
+            clamp_value(self.x, min.x, max.x),
+            clamp_value(self.y, min.y, max.y),
+        )
+    }
+}
+
+impl<T: Copy + PartialOrd + Default + From<i8>> Point2<T> {
+    // Every coordinate becomes -1, 0 or 1, which turns a difference into a single step towards it
+    pub fn signum(&self) -> Self {
+        let get_signum = |value: T| {
+            if value > T::default() {
+                T::from(1)
+            } else if value < T::default() {
+                T::from(-1)
+            } else {
+                T::default()
+            }
+        };
+        Self::new(get_signum(self.x), get_signum(self.y))
+    }
+}
+
+impl Point2<usize> {
+    pub fn checked_add_signed(&self, offset: Point2<isize>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+}
+
+// Directions on a map where y grows downwards, like the rows of the puzzle inputs
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    // In the order west, east, north and south
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Left,
+        Direction::Right,
+        Direction::Up,
+        Direction::Down,
+    ];
+
+    // Row by row from the north west to the south east
+    pub const ALL: [Direction; 8] = [
+        Direction::UpLeft,
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Left,
+        Direction::Right,
+        Direction::DownLeft,
+        Direction::Down,
+        Direction::DownRight,
+    ];
+
+    pub fn get_offset<T: From<i8>>(&self) -> Point2<T> {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::UpLeft => (-1, -1),
+            Direction::UpRight => (1, -1),
+            Direction::DownLeft => (-1, 1),
+            Direction::DownRight => (1, 1),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !Self::ORTHOGONAL.contains(self)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(text: &str) -> std::result::Result<Self, Self::Err> {
+        match text {
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            "UL" => Ok(Direction::UpLeft),
+            "UR" => Ok(Direction::UpRight),
+            "DL" => Ok(Direction::DownLeft),
+            "DR" => Ok(Direction::DownRight),
+            _ => Err(format!("unknown direction `{}`", text)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T>> Point3<T> {
+    pub fn dot(&self, other: Point3<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+impl<T: Copy + PartialOrd> Point3<T> {
+    pub fn is_within(&self, min: Point3<T>, max: Point3<T>) -> bool {
+        (min.x..=max.x).contains(&self.x)
+            && (min.y..=max.y).contains(&self.y)
+            && (min.z..=max.z).contains(&self.z)
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + From<u8>> Point3<T> {
+    // The neighbours sharing a face with the point, checked against the bounds before stepping so
+    // that unsigned coordinates never leave their range
+    pub fn get_face_neighbours_within(&self, min: Point3<T>, max: Point3<T>) -> Vec<Point3<T>> {
+        let one = T::from(1);
+        let mut neighbours = Vec::new();
+
+        if self.x > min.x {
+            neighbours.push(Self::new(self.x - one, self.y, self.z));
+        }
+        if self.x < max.x {
+            neighbours.push(Self::new(self.x + one, self.y, self.z));
+        }
+        if self.y > min.y {
+            neighbours.push(Self::new(self.x, self.y - one, self.z));
+        }
+        if self.y < max.y {
+            neighbours.push(Self::new(self.x, self.y + one, self.z));
+        }
+        if self.z > min.z {
+            neighbours.push(Self::new(self.x, self.y, self.z - one));
+        }
+        if self.z < max.z {
+            neighbours.push(Self::new(self.x, self.y, self.z + one));
+        }
+
+        neighbours
+    }
+
+    pub fn get_face_neighbours(&self) -> [Point3<T>; 6] {
+        let one = T::from(1);
+        [
+            Self::new(self.x - one, self.y, self.z),
+            Self::new(self.x + one, self.y, self.z),
+            Self::new(self.x, self.y - one, self.z),
+            Self::new(self.x, self.y + one, self.z),
+            Self::new(self.x, self.y, self.z - one),
+            Self::new(self.x, self.y, self.z + one),
+        ]
+    }
+}
+
+// Returns the smallest and largest corner of the box containing all points
+pub fn get_bounding_box<'a, T: Copy + Ord + 'a>(
+    points: impl IntoIterator<Item = &'a Point3<T>>,
+) -> Option<(Point3<T>, Point3<T>)> {
+    points.into_iter().fold(None, |bounding_box, point| {
+        let (min, max) = bounding_box.unwrap_or((*point, *point));
+        Some((
+            Point3::new(min.x.min(point.x), min.y.min(point.y), min.z.min(point.z)),
+            Point3::new(max.x.max(point.x), max.y.max(point.y), max.z.max(point.z)),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point2_arithmetic() {
+        let mut point = Point2::new(1, 2) + Point2::new(-3, 1);
+        assert_eq!(point, Point2::new(-2, 3));
+        assert_eq!(point - Point2::new(1, 1), Point2::new(-3, 2));
+        assert_eq!(-point, Point2::new(2, -3));
+        assert_eq!(point * 3, Point2::new(-6, 9));
+        point += Point2::new(2, 2);
+        assert_eq!(point, Point2::new(0, 5));
+        point -= Point2::new(1, 5);
+        assert_eq!(point, Point2::new(-1, 0));
+    }
+
+    #[test]
+    fn test_point2_distances() {
+        let point = Point2::new(1, -2);
+        assert_eq!(point.manhattan_distance(Point2::new(4, 2)), 7);
+        assert_eq!(point.chebyshev_distance(Point2::new(4, 2)), 4);
+        assert_eq!(point.chebyshev_distance(Point2::new(0, -1)), 1);
+        assert_eq!(point.manhattan_distance(point), 0);
+        // Unsigned coordinates in both orders
+        let point = Point2::new(2usize, 7);
+        assert_eq!(point.manhattan_distance(Point2::new(5, 1)), 9);
+        assert_eq!(Point2::new(5, 1).manhattan_distance(point), 9);
+        assert_eq!(point.chebyshev_distance(Point2::new(5, 1)), 6);
+    }
+
+    #[test]
+    fn test_point2_signum_and_clamp() {
+        assert_eq!(Point2::new(-7, 0).signum(), Point2::new(-1, 0));
+        assert_eq!(Point2::new(3i64, -2).signum(), Point2::new(1, -1));
+        let (min, max) = (Point2::new(0, 0), Point2::new(4, 4));
+        assert_eq!(
+            Point2::new(-2, 7).clamp_components(min, max),
+            Point2::new(0, 4)
+        );
+        assert_eq!(
+            Point2::new(2, 3).clamp_components(min, max),
+            Point2::new(2, 3)
+        );
+        assert_eq!(
+            Point2::new(0usize, 3).checked_add_signed(Point2::new(1, -3)),
+            Some(Point2::new(1, 0))
+        );
+        assert_eq!(
+            Point2::new(0usize, 3).checked_add_signed(Point2::new(-1, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!("U".parse(), Ok(Direction::Up));
+        assert_eq!("DL".parse(), Ok(Direction::DownLeft));
+        assert!("X".parse::<Direction>().is_err());
+        assert_eq!(Direction::Up.get_offset::<i32>(), Point2::new(0, -1));
+        assert_eq!(
+            Direction::DownRight.get_offset::<isize>(),
+            Point2::new(1, 1)
+        );
+        assert!(Direction::UpLeft.is_diagonal());
+        assert!(!Direction::Left.is_diagonal());
+        // Opposite directions cancel each other out
+        for (direction, opposite) in [
+            (Direction::Up, Direction::Down),
+            (Direction::Left, Direction::Right),
+            (Direction::UpLeft, Direction::DownRight),
+            (Direction::UpRight, Direction::DownLeft),
+        ] {
+            assert_eq!(
+                direction.get_offset::<i32>() + opposite.get_offset(),
+                Point2::default()
+            );
+        }
+        assert_eq!(Direction::ALL.iter().filter(|d| d.is_diagonal()).count(), 4);
+    }
+
+    #[test]
+    fn test_point_arithmetic() {
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(-1, 1, 2),
+            Point3::new(0, 3, 5)
+        );
+        assert_eq!(
+            Point3::new(1, 2, 3) - Point3::new(-1, 1, 2),
+            Point3::new(2, 1, 1)
+        );
+        assert_eq!(-Point3::new(1, -2, 0), Point3::new(-1, 2, 0));
+        assert_eq!(Point3::new(1, -2, 3) * 2, Point3::new(2, -4, 6));
+        assert_eq!(Point3::new(1, -2, 3).dot(Point3::new(4, 5, 6)), 12);
+    }
+
+    #[test]
+    fn test_face_neighbours() {
+        assert_eq!(
+            Point3::new(0i32, 0, 0).get_face_neighbours(),
+            [
+                Point3::new(-1, 0, 0),
+                Point3::new(1, 0, 0),
+                Point3::new(0, -1, 0),
+                Point3::new(0, 1, 0),
+                Point3::new(0, 0, -1),
+                Point3::new(0, 0, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_face_neighbours_within_bounds() {
+        let min = Point3::new(0usize, 0, 0);
+        let max = Point3::new(2usize, 2, 2);
+        assert_eq!(
+            Point3::new(0, 0, 0).get_face_neighbours_within(min, max),
+            vec![
+                Point3::new(1, 0, 0),
+                Point3::new(0, 1, 0),
+                Point3::new(0, 0, 1)
+            ]
+        );
+        assert_eq!(
+            Point3::new(1, 1, 1)
+                .get_face_neighbours_within(min, max)
+                .len(),
+            6
+        );
+        assert_eq!(
+            Point3::new(2, 2, 1).get_face_neighbours_within(min, max),
+            vec![
+                Point3::new(1, 2, 1),
+                Point3::new(2, 1, 1),
+                Point3::new(2, 2, 0),
+                Point3::new(2, 2, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [
+            Point3::new(1, 5, -2),
+            Point3::new(3, -1, 0),
+            Point3::new(2, 2, 2),
+        ];
+        assert_eq!(
+            get_bounding_box(&points),
+            Some((Point3::new(1, -1, -2), Point3::new(3, 5, 2)))
+        );
+        assert!(Point3::new(2, 0, 0).is_within(Point3::new(1, -1, -2), Point3::new(3, 5, 2)));
+        assert!(!Point3::new(0, 0, 0).is_within(Point3::new(1, -1, -2), Point3::new(3, 5, 2)));
+        assert_eq!(get_bounding_box::<i32>(&[]), None);
+    }
+}
@@ -0,0 +1,423 @@
+use crate::error::{ParseError, Result};
+use crate::geometry::{Direction, Point2};
+use crate::parsing::normalize_input;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+pub type Point = Point2<usize>;
+
+// A rectangular grid stored row by row in a single vector
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // Panics if the rows differ in length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for row in rows {
+            assert_eq!(row.len(), width, "all rows of a grid need the same length");
+            cells.extend(row);
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    // The orthogonal neighbours within the grid, in the order west, east, north and south
+    pub fn get_neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ORTHOGONAL
+            .iter()
+            .filter_map(move |direction| point.checked_add_signed(direction.get_offset()))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    // The orthogonal and diagonal neighbours within the grid, row by row
+    pub fn get_all_neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |direction| point.checked_add_signed(direction.get_offset()))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    // Panics if the row doesn't have the width of the grid
+    pub fn push_row(&mut self, row: Vec<T>) {
+        assert_eq!(
+            row.len(),
+            self.width,
+            "all rows of a grid need the same length"
+        );
+        self.cells.extend(row);
+        self.height += 1;
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Lines and columns start at 1, columns count characters
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GridError {
+    InvalidCell {
+        line: usize,
+        column: usize,
+        cell: char,
+    },
+    RowLength {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidCell { line, column, cell } => {
+                write!(
+                    f,
+                    "invalid cell `{}` in line {}, column {}",
+                    cell, line, column
+                )
+            }
+            Self::RowLength {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} has {} cells instead of {}",
+                line, found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+// Every character of a line is a cell, whitespace around the lines is ignored
+fn parse_cells<T>(
+    input: &str,
+    mut parse_cell: impl FnMut(char) -> Option<T>,
+) -> std::result::Result<Grid<T>, GridError> {
+    let mut grid = Grid::from_rows(Vec::new());
+    for (i, line) in input.lines().enumerate() {
+        let leading_whitespace = line.chars().count() - line.trim_start().chars().count();
+        let row = line
+            .trim()
+            .chars()
+            .enumerate()
+            .map(|(column, cell)| {
+                parse_cell(cell).ok_or(GridError::InvalidCell {
+                    line: i + 1,
+                    column: leading_whitespace + column + 1,
+                    cell,
+                })
+            })
+            .collect::<std::result::Result<Vec<T>, GridError>>()?;
+        if i == 0 {
+            grid.width = row.len();
+        } else if row.len() != grid.width {
+            return Err(GridError::RowLength {
+                line: i + 1,
+                expected: grid.width,
+                found: row.len(),
+            });
+        }
+        grid.push_row(row);
+    }
+    Ok(grid)
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = GridError;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        parse_cells(&normalize_input(input), |cell| T::try_from(cell).ok())
+    }
+}
+
+// Parses a character map with the error positions of the day, the cells of a row are named for
+// errors about rows of different lengths
+pub fn parse_character_map<T>(
+    day: u8,
+    input: &str,
+    expected_cell: &str,
+    cells_name: &str,
+    parse_cell: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>> {
+    let input = &normalize_input(input);
+    parse_cells(input, parse_cell).map_err(|error| match error {
+        GridError::InvalidCell { line, column, .. } => {
+            let line_text = input.lines().nth(line - 1).unwrap();
+            let (offset, cell) = line_text.char_indices().nth(column - 1).unwrap();
+            let cell = &line_text[offset..offset + cell.len_utf8()];
+            ParseError::new(day, line_text, cell, expected_cell).shift_lines(line - 1)
+        }
+        GridError::RowLength { line, expected, .. } => {
+            let line_text = input.lines().nth(line - 1).unwrap();
+            let expected = format!("a line of {} {}", expected, cells_name);
+            ParseError::new(day, line_text, line_text.trim(), &expected).shift_lines(line - 1)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = ();
+
+        fn try_from(c: char) -> std::result::Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                _ => Err(()),
+            }
+        }
+    }
+
+    impl fmt::Display for Tile {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Tile::Open => write!(f, "."),
+                Tile::Wall => write!(f, "#"),
+            }
+        }
+    }
+
+    fn get_numbered_grid() -> Grid<u32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = get_numbered_grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 0)], 3);
+        assert_eq!(grid[Point::new(0, 1)], 4);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        grid[Point::new(1, 1)] = 0;
+        assert_eq!(grid.row(1), &[4, 0, 6]);
+        assert!(grid.get_mut(Point::new(5, 5)).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_indexing_outside() {
+        let grid = get_numbered_grid();
+        let _ = grid[Point::new(3, 0)];
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = get_numbered_grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(
+            grid.column(2).rev().copied().collect::<Vec<_>>(),
+            vec![6, 3]
+        );
+        grid.push_row(vec![7, 8, 9]);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.column(0).copied().collect::<Vec<_>>(), vec![1, 4, 7]);
+    }
+
+    #[test]
+    fn test_points() {
+        let grid = get_numbered_grid();
+        assert_eq!(
+            grid.points().collect::<Vec<_>>(),
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(2, 1),
+            ]
+        );
+        assert_eq!(grid.iter().nth(4), Some((Point::new(1, 1), &5)));
+        assert_eq!(grid.map(|value| value * 2).row(1), &[8, 10, 12]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.get_neighbours(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(
+            grid.get_neighbours(Point::new(1, 1)).collect::<Vec<_>>(),
+            vec![
+                Point::new(0, 1),
+                Point::new(2, 1),
+                Point::new(1, 0),
+                Point::new(1, 2)
+            ]
+        );
+        assert_eq!(
+            grid.get_all_neighbours(Point::new(2, 2))
+                .collect::<Vec<_>>(),
+            vec![Point::new(1, 1), Point::new(2, 1), Point::new(1, 2)]
+        );
+        assert_eq!(grid.get_all_neighbours(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_parsing_and_rendering() {
+        let grid = "#..\n.#.".parse::<Grid<Tile>>().unwrap();
+        assert_eq!(grid.row(0), &[Tile::Wall, Tile::Open, Tile::Open]);
+        assert_eq!(grid[Point::new(1, 1)], Tile::Wall);
+        assert_eq!(grid.to_string(), "#..\n.#.");
+        assert_eq!(grid.to_string().parse::<Grid<Tile>>(), Ok(grid));
+        assert_eq!("".parse::<Grid<Tile>>(), Ok(Grid::from_rows(Vec::new())));
+    }
+
+    #[test]
+    fn test_parsing_errors() {
+        assert_eq!(
+            "#..\n.x.".parse::<Grid<Tile>>(),
+            Err(GridError::InvalidCell {
+                line: 2,
+                column: 2,
+                cell: 'x'
+            })
+        );
+        assert_eq!(
+            "#..\n.#".parse::<Grid<Tile>>(),
+            Err(GridError::RowLength {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+
+        let parse_digit = |c: char| c.to_digit(10);
+        let error = parse_character_map(1, "12\n  3ä", "a digit", "digits", parse_digit);
+        let error = error.unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.text, "ä");
+        assert_eq!(error.expected, "a digit");
+
+        let error = parse_character_map(1, "12\n345", "a digit", "digits", parse_digit);
+        let error = error.unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a line of 2 digits");
+    }
+}
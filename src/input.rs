@@ -0,0 +1,113 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    Default(u8),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn from_argument(day: u8, argument: Option<&str>) -> Self {
+        match argument {
+            None => Self::Default(day),
+            Some("-") => Self::Stdin,
+            Some(path) => Self::File(PathBuf::from(path)),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Default(day) => write!(f, "{}", default_input_path(*day).display()),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: String,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Could not read input from {}: {}",
+            self.source, self.error
+        )
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn input_dir() -> PathBuf {
+    match env::var_os("AOC_INPUT_DIR") {
+        Some(input_dir) => PathBuf::from(input_dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("src/inputs"),
+    }
+}
+
+pub fn default_input_path(day: u8) -> PathBuf {
+    input_dir().join(format!("data_day_{}.txt", day))
+}
+
+pub fn read_input(source: &InputSource) -> Result<String, InputError> {
+    let result = match source {
+        InputSource::Default(day) => fs::read_to_string(default_input_path(*day)),
+        InputSource::File(path) => fs::read_to_string(path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map(|_| input)
+        }
+    };
+    result.map_err(|error| InputError {
+        source: source.to_string(),
+        error,
+    })
+}
+
+pub fn read_input_from_args(day: u8) -> Result<String, InputError> {
+    let arguments = env::args().skip(1).collect::<Vec<String>>();
+    let input_argument = arguments
+        .iter()
+        .position(|argument| argument == "--input" || argument == "-i")
+        .and_then(|i| arguments.get(i + 1))
+        .map(|path| path.as_str());
+    read_input(&InputSource::from_argument(day, input_argument))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source_from_argument() {
+        assert_eq!(InputSource::from_argument(3, None), InputSource::Default(3));
+        assert_eq!(InputSource::from_argument(3, Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_argument(3, Some("input.txt")),
+            InputSource::File(PathBuf::from("input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_default_input_path() {
+        assert!(default_input_path(12).ends_with("data_day_12.txt"));
+    }
+
+    #[test]
+    fn test_read_missing_input() {
+        let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        let error = read_input(&source).unwrap_err();
+        assert_eq!(error.source, "does/not/exist.txt");
+    }
+}
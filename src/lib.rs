@@ -0,0 +1,17 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod days;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parsing;
+pub mod registry;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+
+pub use days::*;
+pub use error::{ParseError, SolveError};
+pub use solution::{Answer, Solution};
@@ -0,0 +1,469 @@
+use crate::error::{ParseError, Result};
+use std::str::FromStr;
+
+// Removes a byte order mark, CRLF line endings, trailing whitespace and trailing blank lines, and
+// ends every non-empty input with exactly one newline
+pub fn normalize_input(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized_input = input
+        .lines()
+        .map(|line| line.trim_end())
+        .collect::<Vec<&str>>()
+        .join("\n");
+    normalized_input.truncate(normalized_input.trim_end().len());
+    if !normalized_input.is_empty() {
+        normalized_input.push('\n');
+    }
+    normalized_input
+}
+
+pub fn parse_token<T: FromStr>(day: u8, line: &str, token: &str, expected: &str) -> Result<T> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(day, line, token, expected))
+}
+
+pub fn split_token<'a>(
+    day: u8,
+    line: &'a str,
+    text: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str)> {
+    text.split_once(delimiter).ok_or_else(|| {
+        ParseError::new(
+            day,
+            line,
+            text,
+            &format!("a text containing `{}`", delimiter),
+        )
+    })
+}
+
+pub fn end_of_line(line: &str) -> &str {
+    &line[line.len()..]
+}
+
+pub fn split_blocks(input: &str) -> Vec<(usize, &str)> {
+    let mut blocks = Vec::new();
+    let mut line_offset = 0;
+    for block in input.split("\n\n") {
+        blocks.push((line_offset, block));
+        line_offset += block.lines().count() + 1;
+    }
+    blocks
+}
+
+// Reads a line from left to right, skipping whitespace between the parts. Every failed step reports
+// the position within the line, shifted to the line's index within the input.
+pub struct LineParser<'a> {
+    day: u8,
+    line: &'a str,
+    rest: &'a str,
+    line_index: usize,
+}
+
+impl<'a> LineParser<'a> {
+    pub fn new(day: u8, line: &'a str, line_index: usize) -> Self {
+        Self {
+            day,
+            line,
+            rest: line,
+            line_index,
+        }
+    }
+
+    pub fn error(&self, text: &str, expected: &str) -> ParseError {
+        ParseError::new(self.day, self.line, text, expected).shift_lines(self.line_index)
+    }
+
+    // Reports the next word, or the end of the line if nothing is left
+    pub fn unexpected(&self, expected: &str) -> ParseError {
+        let rest = self.rest.trim_start();
+        let next_word = rest.split_whitespace().next().unwrap_or(end_of_line(rest));
+        self.error(next_word, expected)
+    }
+
+    fn strip_literal(&self, literal: &str) -> Option<&'a str> {
+        let rest = self.rest.trim_start().strip_prefix(literal)?;
+        // A keyword must not match the start of a longer word, like `old` in `older`
+        let ends_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric());
+        if ends_word(literal.chars().last()) && ends_word(rest.chars().next()) {
+            return None;
+        }
+        Some(rest)
+    }
+
+    pub fn try_literal(&mut self, literal: &str) -> bool {
+        match self.strip_literal(literal) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<()> {
+        if self.try_literal(literal) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("`{}`", literal)))
+        }
+    }
+
+    // Matches the first of the literals and returns its value
+    pub fn one_of<T: Copy>(&mut self, options: &[(&str, T)], expected: &str) -> Result<T> {
+        options
+            .iter()
+            .find(|(literal, _)| self.try_literal(literal))
+            .map(|(_, value)| *value)
+            .ok_or_else(|| self.unexpected(expected))
+    }
+
+    fn number<T: FromStr>(&mut self, allow_sign: bool, expected: &str) -> Result<T> {
+        let rest = self.rest.trim_start();
+        let sign_length = usize::from(allow_sign && rest.starts_with(['-', '+']));
+        let digits_length = rest[sign_length..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign_length);
+        if digits_length == 0 {
+            return Err(self.unexpected(expected));
+        }
+        let (number, rest) = rest.split_at(sign_length + digits_length);
+        // Only fails if the number doesn't fit into the type
+        let value = number.parse().map_err(|_| self.error(number, expected))?;
+        self.rest = rest;
+        Ok(value)
+    }
+
+    pub fn unsigned<T: FromStr>(&mut self, expected: &str) -> Result<T> {
+        self.number(false, expected)
+    }
+
+    pub fn signed<T: FromStr>(&mut self, expected: &str) -> Result<T> {
+        self.number(true, expected)
+    }
+
+    // Parses at least one item, followed by more items as long as the separator follows
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut parse_item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![parse_item(self)?];
+        while self.try_literal(separator) {
+            items.push(parse_item(self)?);
+        }
+        Ok(items)
+    }
+
+    // Also returns the text the parts were parsed from, to report errors found after parsing
+    pub fn consumed<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<(T, &'a str)> {
+        let rest = self.rest;
+        let value = parse(self)?;
+        Ok((value, rest[..rest.len() - self.rest.len()].trim()))
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.rest.trim().is_empty()
+    }
+
+    pub fn end(&self) -> Result<()> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.unexpected("the end of the line"))
+        }
+    }
+}
+
+// Parses a whole line, anything left after the parsed parts is an error
+pub fn parse_line<'a, T>(
+    day: u8,
+    line: &'a str,
+    parse: impl FnOnce(&mut LineParser<'a>) -> Result<T>,
+) -> Result<T> {
+    let mut parser = LineParser::new(day, line, 0);
+    let value = parse(&mut parser)?;
+    parser.end()?;
+    Ok(value)
+}
+
+// Hands out the lines of a block one after another, the line offset is the index of the block's
+// first line within the input
+pub struct BlockParser<'a> {
+    day: u8,
+    lines: Vec<&'a str>,
+    next_line: usize,
+    line_offset: usize,
+}
+
+impl<'a> BlockParser<'a> {
+    pub fn new(day: u8, block: &'a str, line_offset: usize) -> Self {
+        Self {
+            day,
+            lines: block.lines().collect(),
+            next_line: 0,
+            line_offset,
+        }
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.next_line >= self.lines.len()
+    }
+
+    // A missing line is reported at the end of the block's last line
+    pub fn parse_next_line<T>(
+        &mut self,
+        expected: &str,
+        parse: impl FnOnce(&mut LineParser<'a>) -> Result<T>,
+    ) -> Result<T> {
+        let Some(line) = self.lines.get(self.next_line).copied() else {
+            let last_line = self.lines.last().copied().unwrap_or_default();
+            let last_line_index = self.line_offset + self.lines.len().saturating_sub(1);
+            return Err(
+                ParseError::new(self.day, last_line, end_of_line(last_line), expected)
+                    .shift_lines(last_line_index),
+            );
+        };
+        let mut parser = LineParser::new(self.day, line, self.line_offset + self.next_line);
+        self.next_line += 1;
+        let value = parse(&mut parser)?;
+        parser.end()?;
+        Ok(value)
+    }
+
+    pub fn parse_remaining_lines<T>(
+        &mut self,
+        mut parse: impl FnMut(&mut LineParser<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut values = Vec::new();
+        while !self.is_at_end() {
+            values.push(self.parse_next_line("", &mut parse)?);
+        }
+        Ok(values)
+    }
+
+    pub fn end(&self) -> Result<()> {
+        match self.lines.get(self.next_line) {
+            Some(line) => Err(
+                ParseError::new(self.day, line, line.trim(), "the end of the block")
+                    .shift_lines(self.line_offset + self.next_line),
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+// Parses every line of the input with the same format
+pub fn parse_lines<'a, T>(
+    day: u8,
+    input: &'a str,
+    parse: impl FnMut(&mut LineParser<'a>) -> Result<T>,
+) -> Result<Vec<T>> {
+    BlockParser::new(day, input, 0).parse_remaining_lines(parse)
+}
+
+// Parses every block of lines separated by blank lines with the same format, lines left over in a
+// block are an error
+pub fn parse_blocks<'a, T>(
+    day: u8,
+    input: &'a str,
+    mut parse: impl FnMut(&mut BlockParser<'a>) -> Result<T>,
+) -> Result<Vec<T>> {
+    split_blocks(input)
+        .into_iter()
+        .map(|(line_offset, block)| {
+            let mut parser = BlockParser::new(day, block, line_offset);
+            let value = parse(&mut parser)?;
+            parser.end()?;
+            Ok(value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_input() {
+        assert_eq!(normalize_input("1\n2\n\n3\n"), "1\n2\n\n3\n");
+        assert_eq!(normalize_input(""), "");
+        assert_eq!(normalize_input("\n \n"), "");
+    }
+
+    #[test]
+    fn test_normalize_crlf_input() {
+        assert_eq!(normalize_input("1\r\n2\r\n\r\n3\r\n"), "1\n2\n\n3\n");
+    }
+
+    #[test]
+    fn test_normalize_input_with_byte_order_mark() {
+        assert_eq!(normalize_input("\u{feff}    [D]\n"), "    [D]\n");
+    }
+
+    #[test]
+    fn test_normalize_input_with_trailing_whitespace() {
+        assert_eq!(normalize_input("1 \t\n2  \n \n3"), "1\n2\n\n3\n");
+        assert_eq!(normalize_input("1\n2\n\n\n  \n"), "1\n2\n");
+    }
+
+    #[test]
+    fn test_normalize_input_without_final_newline() {
+        assert_eq!(normalize_input("1\n2"), "1\n2\n");
+    }
+
+    #[test]
+    fn test_parse_token() {
+        let line = "addx 12";
+        assert_eq!(
+            parse_token::<i32>(10, line, &line[5..], "an integer"),
+            Ok(12)
+        );
+        let line = "addx 1a";
+        let error = parse_token::<i32>(10, line, &line[5..], "an integer").unwrap_err();
+        assert_eq!(error.column, 6);
+        assert_eq!(error.text, "1a");
+    }
+
+    #[test]
+    fn test_split_token() {
+        let line = "1-2,3-4";
+        assert_eq!(split_token(4, line, line, ","), Ok(("1-2", "3-4")));
+        let error = split_token(4, line, &line[..3], ",").unwrap_err();
+        assert_eq!(error.text, "1-2");
+        assert_eq!(error.expected, "a text containing `,`");
+    }
+
+    #[test]
+    fn test_split_blocks() {
+        assert_eq!(
+            split_blocks("a\nb\n\nc\n\nd\ne"),
+            vec![(0, "a\nb"), (3, "c"), (5, "d\ne")]
+        );
+    }
+
+    #[test]
+    fn test_line_parser() {
+        let line = "  move 12 from -3 to +4";
+        let parsed = parse_line(5, line, |parser| {
+            parser.literal("move")?;
+            let amount: u32 = parser.unsigned("an amount")?;
+            parser.literal("from")?;
+            let from: i32 = parser.signed("a position")?;
+            parser.literal("to")?;
+            let to: i32 = parser.signed("a position")?;
+            Ok((amount, from, to))
+        });
+        assert_eq!(parsed, Ok((12, -3, 4)));
+    }
+
+    #[test]
+    fn test_line_parser_errors() {
+        let error = parse_line(5, "move x", |parser| {
+            parser.literal("move")?;
+            parser.unsigned::<u32>("an amount")
+        })
+        .unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (6, "x"));
+        assert_eq!(error.expected, "an amount");
+
+        let error = parse_line(5, "movement", |parser| parser.literal("move")).unwrap_err();
+        assert_eq!(error.text, "movement");
+        assert_eq!(error.expected, "`move`");
+
+        let error = parse_line(5, "-1", |parser| parser.unsigned::<u32>("a number")).unwrap_err();
+        assert_eq!(error.text, "-1");
+        let error = parse_line(5, "256", |parser| parser.unsigned::<u8>("a byte")).unwrap_err();
+        assert_eq!(error.text, "256");
+
+        let error = parse_line(5, "1 2", |parser| parser.unsigned::<u32>("a number")).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (3, "2"));
+        assert_eq!(error.expected, "the end of the line");
+
+        let error = parse_line(5, "a", |parser| parser.literal("a b")).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (1, "a"));
+    }
+
+    #[test]
+    fn test_one_of_and_separated_list() {
+        let line = "* 1, 2,3";
+        let parsed = parse_line(11, line, |parser| {
+            let operator = parser.one_of(&[("+", '+'), ("*", '*')], "an operator")?;
+            let numbers = parser.separated(",", |parser| parser.unsigned::<u32>("a number"))?;
+            Ok((operator, numbers))
+        });
+        assert_eq!(parsed, Ok(('*', vec![1, 2, 3])));
+
+        let error = parse_line(11, "- 1", |parser| {
+            parser.one_of(&[("+", '+'), ("*", '*')], "an operator")
+        })
+        .unwrap_err();
+        assert_eq!(
+            (error.text.as_str(), error.expected.as_str()),
+            ("-", "an operator")
+        );
+
+        let error = parse_line(11, "1,", |parser| {
+            parser.separated(",", |parser| parser.unsigned::<u32>("a number"))
+        })
+        .unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (3, ""));
+    }
+
+    #[test]
+    fn test_consumed() {
+        let error = parse_line(9, "R 1, 2", |parser| {
+            parser.literal("R")?;
+            let (numbers, text) = parser.consumed(|parser| {
+                parser.separated(",", |parser| parser.unsigned::<u32>("a number"))
+            })?;
+            Err::<(), _>(parser.error(text, &format!("{} numbers", numbers.len() + 1)))
+        })
+        .unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (3, "1, 2"));
+        assert_eq!(error.expected, "3 numbers");
+    }
+
+    #[test]
+    fn test_line_parser_at_end() {
+        let mut parser = LineParser::new(5, "move 1  ", 0);
+        assert!(!parser.is_at_end());
+        parser.literal("move").unwrap();
+        parser.unsigned::<u32>("a number").unwrap();
+        assert!(parser.is_at_end());
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parse_number = |parser: &mut LineParser| parser.signed::<i32>("a number");
+        assert_eq!(parse_lines(1, "1\n-2\n", parse_number), Ok(vec![1, -2]));
+        let error = parse_lines(1, "1\n2\nx3\n", parse_number).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let parse_pair = |parser: &mut BlockParser| {
+            let left = parser.parse_next_line("a number", |parser| parser.unsigned("a number"))?;
+            let right = parser.parse_next_line("a number", |parser| parser.unsigned("a number"))?;
+            Ok((left, right))
+        };
+        assert_eq!(
+            parse_blocks(13, "1\n2\n\n3\n4\n", parse_pair),
+            Ok(vec![(1u32, 2u32), (3, 4)])
+        );
+
+        let error = parse_blocks(13, "1\n2\n\n3\n", parse_pair).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 2));
+        assert_eq!(error.expected, "a number");
+
+        let error = parse_blocks(13, "1\n2\n5\n\n3\n4\n", parse_pair).unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (3, "5"));
+        assert_eq!(error.expected, "the end of the block");
+    }
+}
@@ -0,0 +1,115 @@
+use crate::bench::{benchmark_solution, Benchmark, Timings};
+use crate::days::*;
+use crate::error::SolveResult;
+use crate::solution::{Answer, Solution};
+
+pub struct Solver {
+    pub day: u8,
+    pub title: &'static str,
+    pub parts: [fn(&str) -> SolveResult<Answer>; 2],
+    pub bench: fn(&str, usize) -> SolveResult<[Timings; 3]>,
+}
+
+impl Solver {
+    pub const fn new<S: Solution>(day: u8, title: &'static str) -> Self {
+        Self {
+            day,
+            title,
+            parts: [S::solve_part_1, S::solve_part_2],
+            bench: benchmark_solution::<S>,
+        }
+    }
+
+    pub fn solve(&self, part: usize, input: &str) -> SolveResult<Answer> {
+        (self.parts[part - 1])(input)
+    }
+
+    pub fn benchmark(&self, input: &str, iterations: usize) -> SolveResult<Benchmark> {
+        let [parse, part_1, part_2] = (self.bench)(input, iterations)?;
+        Ok(Benchmark {
+            day: self.day,
+            iterations: iterations.max(1),
+            parse,
+            part_1,
+            part_2,
+        })
+    }
+}
+
+pub static SOLVERS: &[Solver] = &[
+    Solver::new::<day_01::CalorieCounting>(1, "Calorie Counting"),
+    Solver::new::<day_02::RockPaperScissors>(2, "Rock Paper Scissors"),
+    Solver::new::<day_03::RucksackReorganization>(3, "Rucksack Reorganization"),
+    Solver::new::<day_04::CampCleanup>(4, "Camp Cleanup"),
+    Solver::new::<day_05::SupplyStacks>(5, "Supply Stacks"),
+    Solver::new::<day_06::TuningTrouble>(6, "Tuning Trouble"),
+    Solver::new::<day_07::NoSpaceLeftOnDevice>(7, "No Space Left On Device"),
+    Solver::new::<day_08::TreetopTreeHouse>(8, "Treetop Tree House"),
+    Solver::new::<day_09::RopeBridge>(9, "Rope Bridge"),
+    Solver::new::<day_10::CathodeRayTube>(10, "Cathode-Ray Tube"),
+    Solver::new::<day_11::MonkeyInTheMiddle>(11, "Monkey in the Middle"),
+    Solver::new::<day_12::HillClimbingAlgorithm>(12, "Hill Climbing Algorithm"),
+    Solver::new::<day_13::DistressSignal>(13, "Distress Signal"),
+    Solver::new::<day_14::RegolithReservoir>(14, "Regolith Reservoir"),
+    Solver::new::<day_15::BeaconExclusionZone>(15, "Beacon Exclusion Zone"),
+    Solver::new::<day_16::ProboscideaVolcanium>(16, "Proboscidea Volcanium"),
+    Solver::new::<day_17::PyroclasticFlow>(17, "Pyroclastic Flow"),
+    Solver::new::<day_18::BoilingBoulders>(18, "Boiling Boulders"),
+    Solver::new::<day_19::NotEnoughMinerals>(19, "Not Enough Minerals"),
+    Solver::new::<day_20::GrovePositioningSystem>(20, "Grove Positioning System"),
+    Solver::new::<day_21::MonkeyMath>(21, "Monkey Math"),
+    Solver::new::<day_22::MonkeyMap>(22, "Monkey Map"),
+    Solver::new::<day_23::UnstableDiffusion>(23, "Unstable Diffusion"),
+    Solver::new::<day_24::BlizzardBasin>(24, "Blizzard Basin"),
+    Solver::new::<day_25::FullOfHotAir>(25, "Full Of Hot Air"),
+];
+
+// Days whose examples are solved with different parameters than the puzzle inputs
+pub static EXAMPLE_SOLVERS: &[Solver] = &[Solver::new::<day_15::BeaconExclusionZone<10, 20>>(
+    15,
+    "Beacon Exclusion Zone",
+)];
+
+pub fn find_solver(day: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|solver| solver.day == day)
+}
+
+pub fn find_example_solver(day: u8) -> Option<&'static Solver> {
+    EXAMPLE_SOLVERS
+        .iter()
+        .find(|solver| solver.day == day)
+        .or_else(|| find_solver(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solvers_are_ordered_by_day() {
+        for (i, solver) in SOLVERS.iter().enumerate() {
+            assert_eq!(solver.day as usize, i + 1);
+        }
+    }
+
+    #[test]
+    fn test_find_solver() {
+        assert_eq!(find_solver(5).unwrap().title, "Supply Stacks");
+        assert!(find_solver(26).is_none());
+    }
+
+    #[test]
+    fn test_find_example_solver() {
+        let input = "Sensor at x=8, y=7: closest beacon is at x=2, y=10";
+        assert_eq!(
+            find_example_solver(15).unwrap().solve(1, input),
+            Ok(Answer::Integer(12))
+        );
+        assert_eq!(
+            find_solver(15).unwrap().solve(1, input),
+            Ok(Answer::Integer(0))
+        );
+        assert_eq!(find_example_solver(5).unwrap().title, "Supply Stacks");
+        assert!(find_example_solver(26).is_none());
+    }
+}
@@ -0,0 +1,381 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub struct ScaffoldError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Could not scaffold {}: {}",
+            self.path.display(),
+            self.message
+        )
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+pub fn get_solution_name(day: u8, title: &str) -> String {
+    let name = title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word[..1].to_ascii_uppercase() + &word[1..])
+        .collect::<String>();
+    if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("Day{:02}{}", day, name)
+    }
+}
+
+pub fn get_module_template(name: &str) -> String {
+    format!(
+        "use crate::error::{{Result, SolveResult}};
+use crate::parsing::normalize_input;
+use crate::solution::{{Answer, Solution}};
+
+pub fn parse_input(input: &str) -> Result<Vec<String>> {{
+    let input = &normalize_input(input);
+    Ok(input.lines().map(String::from).collect())
+}}
+
+pub struct {name};
+
+impl Solution for {name} {{
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        parse_input(input)
+    }}
+
+    fn part1(lines: &Self::Input) -> SolveResult<Answer> {{
+        Ok(lines.len().into())
+    }}
+
+    fn part2(lines: &Self::Input) -> SolveResult<Answer> {{
+        Ok(lines.len().into())
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn test_input_parsing() {{
+        assert_eq!(parse_input(\"a\\nb\").unwrap(), vec![\"a\", \"b\"]);
+    }}
+}}
+"
+    )
+}
+
+pub fn get_bin_template(day: u8, name: &str) -> String {
+    format!(
+        "use aoc2022::cli::exit_with_error;
+use aoc2022::day_{day:02}::{name};
+use aoc2022::input::read_input_from_args;
+use aoc2022::Solution;
+
+fn main() {{
+    let raw_input = read_input_from_args({day}).unwrap_or_else(|error| exit_with_error(error));
+    let input = {name}::parse(&raw_input).unwrap_or_else(|error| exit_with_error(error));
+
+    // Solution for puzzle 1
+    println!(
+        \"The answer to part 1 is {{}}\",
+        {name}::part1(&input).unwrap_or_else(|error| exit_with_error(error))
+    );
+
+    // Solution for puzzle 2
+    println!(
+        \"The answer to part 2 is {{}}\",
+        {name}::part2(&input).unwrap_or_else(|error| exit_with_error(error))
+    );
+}}
+"
+    )
+}
+
+// Inserts the line before the first line of the same kind with a higher day, or after the last
+// line of that kind, to keep the days ordered
+fn insert_ordered_line(
+    text: &str,
+    line: &str,
+    day: u8,
+    get_day: impl Fn(&str) -> Option<u8>,
+) -> Option<String> {
+    let lines = text.lines().collect::<Vec<&str>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| get_day(line).map(|line_day| (i, line_day)))
+        .collect::<Vec<(usize, u8)>>();
+    let position = match days.iter().find(|(_, line_day)| *line_day > day) {
+        Some((i, _)) => *i,
+        None => days.last()?.0 + 1,
+    };
+    let mut lines = lines;
+    lines.insert(position, line);
+    Some(lines.join("\n") + "\n")
+}
+
+pub fn register_module(days_module: &str, day: u8) -> Option<String> {
+    insert_ordered_line(
+        days_module,
+        &format!("pub mod day_{:02};", day),
+        day,
+        |line| {
+            line.strip_prefix("pub mod day_")?
+                .strip_suffix(';')?
+                .parse()
+                .ok()
+        },
+    )
+}
+
+pub fn register_solver(registry: &str, day: u8, name: &str, title: &str) -> Option<String> {
+    // The debug format quotes and escapes the title, so it is always a valid string literal
+    let solver_line = format!(
+        "    Solver::new::<day_{:02}::{}>({}, {:?}),",
+        day, name, day, title
+    );
+    insert_ordered_line(registry, &solver_line, day, |line| {
+        let arguments = line
+            .trim()
+            .strip_prefix("Solver::new::<")?
+            .split_once(">(")?
+            .1;
+        arguments.split_once(',')?.0.parse().ok()
+    })
+}
+
+pub fn register_example_test(examples: &str, day: u8) -> Option<String> {
+    let test_line = format!("    test_day_{:02}_example: {},", day, day);
+    insert_ordered_line(examples, &test_line, day, |line| {
+        let day = line.trim().strip_prefix("test_day_")?.split_once(':')?.1;
+        day.trim()
+            .split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok()
+    })
+}
+
+fn read_file(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|error| ScaffoldError {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+    })
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+    let result = match path.parent() {
+        Some(parent) => fs::create_dir_all(parent).and_then(|_| fs::write(path, content)),
+        None => fs::write(path, content),
+    };
+    result.map_err(|error: io::Error| ScaffoldError {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+    })
+}
+
+// All files are checked and generated before anything is written, so a failing day doesn't leave
+// a half registered day behind
+pub fn create_day(root: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let name = get_solution_name(day, title);
+    let fixture_dir = root.join(format!("tests/fixtures/day_{}", day));
+    let new_files = [
+        (
+            root.join(format!("src/days/day_{:02}.rs", day)),
+            get_module_template(&name),
+        ),
+        (
+            root.join(format!("src/bin/day_{}.rs", day)),
+            get_bin_template(day, &name),
+        ),
+        (
+            root.join(format!("src/inputs/data_day_{}.txt", day)),
+            String::new(),
+        ),
+        (fixture_dir.join("example.txt"), String::new()),
+        (
+            fixture_dir.join("expected.toml"),
+            format!("[day_{}]\n", day),
+        ),
+    ];
+    for (path, _) in &new_files {
+        if path.exists() {
+            return Err(ScaffoldError {
+                path: path.clone(),
+                message: String::from("the file already exists"),
+            });
+        }
+    }
+
+    let days_module_path = root.join("src/days/mod.rs");
+    let registry_path = root.join("src/registry.rs");
+    let examples_path = root.join("tests/examples.rs");
+    let updated_files = [
+        (
+            register_module(&read_file(&days_module_path)?, day),
+            days_module_path,
+        ),
+        (
+            register_solver(&read_file(&registry_path)?, day, &name, title),
+            registry_path,
+        ),
+        (
+            register_example_test(&read_file(&examples_path)?, day),
+            examples_path,
+        ),
+    ]
+    .into_iter()
+    .map(|(updated_text, path)| match updated_text {
+        Some(updated_text) => Ok((path, updated_text)),
+        None => Err(ScaffoldError {
+            path,
+            message: String::from("no existing day to register the new day next to"),
+        }),
+    })
+    .collect::<Result<Vec<(PathBuf, String)>, ScaffoldError>>()?;
+
+    let mut written_files = Vec::new();
+    for (path, content) in new_files.into_iter().chain(updated_files) {
+        write_file(&path, &content)?;
+        written_files.push(path);
+    }
+    Ok(written_files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_solution_name() {
+        assert_eq!(
+            get_solution_name(15, "Beacon Exclusion Zone"),
+            "BeaconExclusionZone"
+        );
+        assert_eq!(get_solution_name(10, "Cathode-Ray Tube"), "CathodeRayTube");
+        assert_eq!(
+            get_solution_name(16, "proboscidea volcanium"),
+            "ProboscideaVolcanium"
+        );
+        assert_eq!(get_solution_name(7, ""), "Day07");
+        assert_eq!(get_solution_name(7, "42"), "Day0742");
+    }
+
+    #[test]
+    fn test_register_module() {
+        assert_eq!(
+            register_module("pub mod day_01;\npub mod day_03;\n", 2).unwrap(),
+            "pub mod day_01;\npub mod day_02;\npub mod day_03;\n"
+        );
+        assert_eq!(
+            register_module("pub mod day_14;\n", 15).unwrap(),
+            "pub mod day_14;\npub mod day_15;\n"
+        );
+        assert_eq!(register_module("", 15), None);
+    }
+
+    #[test]
+    fn test_register_solver() {
+        let registry = "pub static SOLVERS: &[Solver] = &[
+    Solver::new::<day_13::DistressSignal>(13, \"Distress Signal\"),
+    Solver::new::<day_14::RegolithReservoir>(14, \"Regolith Reservoir\"),
+];
+";
+        assert_eq!(
+            register_solver(registry, 15, "BeaconExclusionZone", "Beacon Exclusion Zone").unwrap(),
+            "pub static SOLVERS: &[Solver] = &[
+    Solver::new::<day_13::DistressSignal>(13, \"Distress Signal\"),
+    Solver::new::<day_14::RegolithReservoir>(14, \"Regolith Reservoir\"),
+    Solver::new::<day_15::BeaconExclusionZone>(15, \"Beacon Exclusion Zone\"),
+];
+"
+        );
+    }
+
+    #[test]
+    fn test_register_solver_escapes_title() {
+        let registry = "pub static SOLVERS: &[Solver] = &[
+    Solver::new::<day_02::RockPaperScissors>(2, \"Rock Paper Scissors\"),
+];
+";
+        assert_eq!(
+            register_solver(registry, 3, "Quoted", "A \"quoted\" \\ title").unwrap(),
+            "pub static SOLVERS: &[Solver] = &[
+    Solver::new::<day_02::RockPaperScissors>(2, \"Rock Paper Scissors\"),
+    Solver::new::<day_03::Quoted>(3, \"A \\\"quoted\\\" \\\\ title\"),
+];
+"
+        );
+    }
+
+    #[test]
+    fn test_register_example_test() {
+        let examples = "example_tests! {
+    test_day_09_example: 9,
+    test_day_10_example: 10,
+    test_day_16_example: 16 => ValveSolution<10>,
+}
+";
+        assert_eq!(
+            register_example_test(examples, 15).unwrap(),
+            "example_tests! {
+    test_day_09_example: 9,
+    test_day_10_example: 10,
+    test_day_15_example: 15,
+    test_day_16_example: 16 => ValveSolution<10>,
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = env::temp_dir().join(format!("aoc2022_scaffold_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write_file(&root.join("src/days/mod.rs"), "pub mod day_14;\n").unwrap();
+        write_file(
+            &root.join("src/registry.rs"),
+            "    Solver::new::<day_14::RegolithReservoir>(14, \"Regolith Reservoir\"),\n",
+        )
+        .unwrap();
+        write_file(
+            &root.join("tests/examples.rs"),
+            "    test_day_14_example: 14,\n",
+        )
+        .unwrap();
+
+        let written_files = create_day(&root, 15, "Beacon Exclusion Zone").unwrap();
+        assert_eq!(written_files.len(), 8);
+        let module = read_file(&root.join("src/days/day_15.rs")).unwrap();
+        assert!(module.contains("impl Solution for BeaconExclusionZone {"));
+        let bin = read_file(&root.join("src/bin/day_15.rs")).unwrap();
+        assert!(bin.contains("use aoc2022::day_15::BeaconExclusionZone;"));
+        assert!(bin.contains("read_input_from_args(15)"));
+        assert_eq!(
+            read_file(&root.join("src/inputs/data_day_15.txt")).unwrap(),
+            ""
+        );
+        assert_eq!(
+            read_file(&root.join("src/days/mod.rs")).unwrap(),
+            "pub mod day_14;\npub mod day_15;\n"
+        );
+
+        let error = create_day(&root, 15, "Beacon Exclusion Zone").unwrap_err();
+        assert!(error.path.ends_with("src/days/day_15.rs"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
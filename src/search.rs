@@ -0,0 +1,278 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SearchResult<N, C> {
+    pub cost: C,
+    // From the start node to the goal node, both included
+    pub path: Vec<N>,
+}
+
+// Gives every visited node an index and remembers from which node it was reached, so the path can
+// be reconstructed without requiring more than Clone, Eq and Hash from the nodes
+struct SearchTree<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<N: Clone + Eq + Hash> SearchTree<N> {
+    fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            parents: Vec::new(),
+        }
+    }
+
+    fn get_index(&self, node: &N) -> Option<usize> {
+        self.indices.get(node).copied()
+    }
+
+    fn insert(&mut self, node: N, parent: Option<usize>) -> usize {
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.parents.push(parent);
+        index
+    }
+
+    fn get_path(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].clone()];
+        while let Some(parent) = self.parents[index] {
+            path.push(self.nodes[parent].clone());
+            index = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+// Finds the path with the fewest steps from any of the start nodes to a goal node
+pub fn breadth_first_search<N, I>(
+    start_nodes: impl IntoIterator<Item = N>,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut get_neighbours: impl FnMut(&N) -> I,
+) -> Option<SearchResult<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = SearchTree::new();
+    let mut search_queue = VecDeque::new();
+    for node in start_nodes {
+        if tree.get_index(&node).is_none() {
+            search_queue.push_back((tree.insert(node, None), 0));
+        }
+    }
+
+    while let Some((index, steps)) = search_queue.pop_front() {
+        if is_goal(&tree.nodes[index]) {
+            return Some(SearchResult {
+                cost: steps,
+                path: tree.get_path(index),
+            });
+        }
+        for neighbour in get_neighbours(&tree.nodes[index]) {
+            if tree.get_index(&neighbour).is_none() {
+                search_queue.push_back((tree.insert(neighbour, Some(index)), steps + 1));
+            }
+        }
+    }
+    None
+}
+
+// Finds the cheapest path from any of the start nodes to a goal node, the costs of the edges must
+// not be negative
+pub fn dijkstra_search<N, C, I>(
+    start_nodes: impl IntoIterator<Item = N>,
+    is_goal: impl FnMut(&N) -> bool,
+    get_neighbours: impl FnMut(&N) -> I,
+) -> Option<SearchResult<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    a_star_search(start_nodes, is_goal, get_neighbours, |_| C::default())
+}
+
+// Like Dijkstra's algorithm, but prefers nodes with a low estimated cost to the goal. The path is
+// the cheapest one as long as the heuristic never overestimates the remaining cost.
+pub fn a_star_search<N, C, I>(
+    start_nodes: impl IntoIterator<Item = N>,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut get_neighbours: impl FnMut(&N) -> I,
+    mut get_heuristic: impl FnMut(&N) -> C,
+) -> Option<SearchResult<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree = SearchTree::new();
+    let mut costs = Vec::new();
+    let mut search_queue = BinaryHeap::new();
+    for node in start_nodes {
+        if tree.get_index(&node).is_none() {
+            let estimate = get_heuristic(&node);
+            let index = tree.insert(node, None);
+            costs.push(C::default());
+            search_queue.push(Reverse((estimate, C::default(), index)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = search_queue.pop() {
+        // A cheaper way to the node was found after this entry was queued
+        if cost > costs[index] {
+            continue;
+        }
+        if is_goal(&tree.nodes[index]) {
+            return Some(SearchResult {
+                cost,
+                path: tree.get_path(index),
+            });
+        }
+        for (neighbour, edge_cost) in get_neighbours(&tree.nodes[index]) {
+            let neighbour_cost = cost + edge_cost;
+            let neighbour_index = match tree.get_index(&neighbour) {
+                Some(neighbour_index) if costs[neighbour_index] <= neighbour_cost => continue,
+                Some(neighbour_index) => {
+                    costs[neighbour_index] = neighbour_cost;
+                    tree.parents[neighbour_index] = Some(index);
+                    neighbour_index
+                }
+                None => {
+                    costs.push(neighbour_cost);
+                    tree.insert(neighbour, Some(index))
+                }
+            };
+            let estimate = neighbour_cost + get_heuristic(&tree.nodes[neighbour_index]);
+            search_queue.push(Reverse((estimate, neighbour_cost, neighbour_index)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A cheap but long way from `a` to `d` over `b` and `c`, and an expensive direct edge
+    fn get_weighted_neighbours(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    fn get_grid_neighbours((x, y): &(i32, i32)) -> Vec<((i32, i32), u32)> {
+        // A wall at x = 2 with a gap at y = 4
+        [(x - 1, *y), (x + 1, *y), (*x, y - 1), (*x, y + 1)]
+            .into_iter()
+            .filter(|(x, y)| (0..5).contains(x) && (0..5).contains(y) && (*x != 2 || *y == 4))
+            .map(|neighbour| (neighbour, 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_breadth_first_search() {
+        let result = breadth_first_search(
+            ['a'],
+            |node| *node == 'd',
+            |node| get_weighted_neighbours(node).into_iter().map(|(n, _)| n),
+        );
+        assert_eq!(
+            result,
+            Some(SearchResult {
+                cost: 1,
+                path: vec!['a', 'd']
+            })
+        );
+
+        let result = breadth_first_search(
+            [(0, 0)],
+            |node| *node == (4, 0),
+            |node| get_grid_neighbours(node).into_iter().map(|(n, _)| n),
+        )
+        .unwrap();
+        assert_eq!(result.cost, 12);
+        assert_eq!(result.path.len(), 13);
+        assert!(result.path.contains(&(2, 4)));
+    }
+
+    #[test]
+    fn test_dijkstra_search() {
+        let result = dijkstra_search(['a'], |node| *node == 'd', get_weighted_neighbours);
+        assert_eq!(
+            result,
+            Some(SearchResult {
+                cost: 3,
+                path: vec!['a', 'b', 'c', 'd']
+            })
+        );
+    }
+
+    #[test]
+    fn test_a_star_search() {
+        let goal = (4, 0);
+        let get_manhattan_distance =
+            |(x, y): &(i32, i32)| (goal.0 - x).unsigned_abs() + (goal.1 - y).unsigned_abs();
+        let result = a_star_search(
+            [(0, 0)],
+            |node| *node == goal,
+            get_grid_neighbours,
+            get_manhattan_distance,
+        )
+        .unwrap();
+        let dijkstra_result =
+            dijkstra_search([(0, 0)], |node| *node == goal, get_grid_neighbours).unwrap();
+        assert_eq!(result.cost, 12);
+        assert_eq!(result.cost, dijkstra_result.cost);
+        assert_eq!(result.path.first(), Some(&(0, 0)));
+        assert_eq!(result.path.last(), Some(&goal));
+        for step in result.path.windows(2) {
+            assert!(get_grid_neighbours(&step[0]).contains(&(step[1], 1)));
+        }
+    }
+
+    #[test]
+    fn test_multiple_start_nodes() {
+        let result = dijkstra_search(['a', 'c'], |node| *node == 'd', get_weighted_neighbours);
+        assert_eq!(
+            result,
+            Some(SearchResult {
+                cost: 1,
+                path: vec!['c', 'd']
+            })
+        );
+        let result = breadth_first_search(['d', 'a'], |node| *node == 'd', |_| Vec::new());
+        assert_eq!(
+            result,
+            Some(SearchResult {
+                cost: 0,
+                path: vec!['d']
+            })
+        );
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        assert_eq!(
+            dijkstra_search(['b'], |node| *node == 'a', get_weighted_neighbours),
+            None
+        );
+        assert_eq!(
+            breadth_first_search(['b'], |node| *node == 'a', |_| Vec::new()),
+            None
+        );
+        assert_eq!(
+            a_star_search(Vec::<char>::new(), |_| true, get_weighted_neighbours, |_| 0),
+            None
+        );
+    }
+}
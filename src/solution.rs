@@ -0,0 +1,115 @@
+use crate::error::{Result, SolveResult};
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::Integer(
+                        i64::try_from(value).expect("integer answers have to fit into an i64"),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(String::from(text))
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> SolveResult<Answer>;
+    fn part2(input: &Self::Input) -> SolveResult<Answer>;
+
+    fn solve_part_1(input: &str) -> SolveResult<Answer> {
+        Self::part1(&Self::parse(input)?)
+    }
+
+    fn solve_part_2(input: &str) -> SolveResult<Answer> {
+        Self::part2(&Self::parse(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{ParseError, SolveError};
+
+    struct WordCount;
+
+    impl Solution for WordCount {
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            if input.is_empty() {
+                return Err(ParseError::new(0, input, input, "a word"));
+            }
+            Ok(input.split_whitespace().map(String::from).collect())
+        }
+
+        fn part1(input: &Self::Input) -> SolveResult<Answer> {
+            Ok(input.len().into())
+        }
+
+        fn part2(input: &Self::Input) -> SolveResult<Answer> {
+            if input.len() < 2 {
+                return Err(SolveError::no_answer(0, 2, "a single word can't be joined"));
+            }
+            Ok(input.join("-").into())
+        }
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(-12).to_string(), "-12");
+        assert_eq!(Answer::from(2713310158u64).to_string(), "2713310158");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+    }
+
+    #[test]
+    fn test_solve_parts() {
+        assert_eq!(WordCount::solve_part_1("a b c"), Ok(Answer::Integer(3)));
+        assert_eq!(
+            WordCount::solve_part_2("a b c"),
+            Ok(Answer::Text(String::from("a-b-c")))
+        );
+        assert!(matches!(
+            WordCount::solve_part_1(""),
+            Err(SolveError::Parse(_))
+        ));
+        assert_eq!(
+            WordCount::solve_part_2("a"),
+            Err(SolveError::no_answer(0, 2, "a single word can't be joined"))
+        );
+    }
+}
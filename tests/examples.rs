@@ -0,0 +1,133 @@
+use aoc2022::answers::{ExpectedAnswers, Verification};
+use aoc2022::day_15::BeaconExclusionZone;
+use aoc2022::registry::{find_solver, Solver, SOLVERS};
+use aoc2022::SolveError;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn fixture_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(format!("day_{}", day))
+}
+
+fn read_fixture(day: u8, file_name: &str) -> String {
+    let path = fixture_dir(day).join(file_name);
+    fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("Could not read {}: {}", path.display(), error))
+}
+
+// The same example saved by different editors and platforms has to give the same answers
+fn get_input_variants(input: &str) -> Vec<(&'static str, String)> {
+    vec![
+        ("original", String::from(input)),
+        ("crlf", input.replace('\n', "\r\n")),
+        ("byte order mark", format!("\u{feff}{}", input)),
+        ("trailing whitespace", input.replace('\n', " \t\n")),
+        (
+            "missing final newline",
+            String::from(input.trim_end_matches('\n')),
+        ),
+        ("trailing blank lines", format!("{}\n\n  \n", input)),
+    ]
+}
+
+fn check_example(solver: &Solver) {
+    let day = solver.day;
+    let input = read_fixture(day, "example.txt");
+    let expected_answers = ExpectedAnswers::parse(&read_fixture(day, "expected.toml"))
+        .unwrap_or_else(|error| panic!("{}", error));
+
+    for (variant, input) in get_input_variants(&input) {
+        for part in [1, 2] {
+            let answer = match solver.solve(part, &input) {
+                Ok(answer) => answer,
+                Err(SolveError::NotApplicable { .. }) => {
+                    assert_eq!(
+                        expected_answers.get(day, part),
+                        None,
+                        "Day {} has no part {}, but an answer is expected",
+                        day,
+                        part
+                    );
+                    continue;
+                }
+                Err(error) => panic!("{} input:\n{}", variant, error),
+            };
+            assert_eq!(
+                expected_answers.verify(day, part, &answer),
+                Verification::Correct,
+                "Day {}, part {} with {} input found:\n{}",
+                day,
+                part,
+                variant,
+                answer
+            );
+        }
+    }
+}
+
+// Days with parameters that differ between the example and the puzzle input name the solution
+// configured for the example, all other days use the registered solver
+macro_rules! example_solver {
+    ($day:literal) => {
+        find_solver($day).unwrap()
+    };
+    ($day:literal, $solution:ty) => {
+        &Solver::new::<$solution>($day, "")
+    };
+}
+
+macro_rules! example_tests {
+    ($($test_name:ident: $day:literal $(=> $solution:ty)?,)*) => {
+        $(
+            #[test]
+            fn $test_name() {
+                check_example(example_solver!($day $(, $solution)?));
+            }
+        )*
+    };
+}
+
+example_tests! {
+    test_day_01_example: 1,
+    test_day_02_example: 2,
+    test_day_03_example: 3,
+    test_day_04_example: 4,
+    test_day_05_example: 5,
+    test_day_06_example: 6,
+    test_day_07_example: 7,
+    test_day_08_example: 8,
+    test_day_09_example: 9,
+    test_day_10_example: 10,
+    test_day_11_example: 11,
+    test_day_12_example: 12,
+    test_day_13_example: 13,
+    test_day_14_example: 14,
+    test_day_15_example: 15 => BeaconExclusionZone<10, 20>,
+    test_day_16_example: 16,
+    test_day_17_example: 17,
+    test_day_18_example: 18,
+    test_day_19_example: 19,
+    test_day_20_example: 20,
+    test_day_21_example: 21,
+    test_day_22_example: 22,
+    test_day_23_example: 23,
+    test_day_24_example: 24,
+    test_day_25_example: 25,
+}
+
+#[test]
+fn test_every_day_has_fixtures() {
+    for solver in SOLVERS {
+        for file_name in ["example.txt", "expected.toml"] {
+            assert!(
+                fixture_dir(solver.day).join(file_name).is_file(),
+                "Day {} is missing tests/fixtures/day_{}/{}",
+                solver.day,
+                solver.day,
+                file_name
+            );
+        }
+    }
+}